@@ -14,49 +14,27 @@ export type BioxResearch = {
   },
   "instructions": [
     {
-      "name": "claimFunds",
+      "name": "acceptAdmin",
       "docs": [
-        "Claim funds (only by author when fully funded)"
+        "Accept a pending admin proposal (proposed admin only)"
       ],
       "discriminator": [
-        145,
-        36,
-        143,
-        242,
-        168,
-        66,
-        200,
-        155
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
       ],
       "accounts": [
         {
-          "name": "author",
+          "name": "newAdmin",
           "signer": true
         },
         {
-          "name": "paper",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  97,
-                  112,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "paperId"
-              }
-            ]
-          }
-        },
-        {
-          "name": "paperTokenAccount",
+          "name": "programState",
           "writable": true,
           "pda": {
             "seeds": [
@@ -64,27 +42,57 @@ export type BioxResearch = {
                 "kind": "const",
                 "value": [
                   112,
-                  97,
-                  112,
-                  101,
                   114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
                   45,
+                  115,
                   116,
-                  111,
-                  107,
-                  101,
-                  110
+                  97,
+                  116,
+                  101
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "paperId"
               }
             ]
           }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCitation",
+      "docs": [
+        "Register a citation from the author's paper to another paper on the platform"
+      ],
+      "discriminator": [
+        150,
+        122,
+        52,
+        129,
+        96,
+        64,
+        22,
+        167
+      ],
+      "accounts": [
+        {
+          "name": "author",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "authorTokenAccount",
+          "name": "citingPaper",
+          "writable": true
+        },
+        {
+          "name": "citedPaper",
+          "writable": true
+        },
+        {
+          "name": "citation",
           "writable": true
         },
         {
@@ -113,150 +121,124 @@ export type BioxResearch = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "paperId",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "fundPaper",
+      "name": "addEditor",
       "docs": [
-        "Fund a published paper"
+        "Grant the editor role (admin only)"
       ],
       "discriminator": [
-        223,
-        208,
-        122,
-        79,
-        221,
-        8,
-        184,
-        180
+        22,
+        77,
+        113,
+        220,
+        140,
+        3,
+        167,
+        45
       ],
       "accounts": [
         {
-          "name": "funder",
+          "name": "admin",
           "writable": true,
           "signer": true
         },
         {
-          "name": "paper",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  97,
-                  112,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "paperId"
-              }
-            ]
-          }
-        },
-        {
-          "name": "funderTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "paperTokenAccount",
+          "name": "editorAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  97,
-                  112,
                   101,
-                  114,
-                  45,
+                  100,
+                  105,
                   116,
                   111,
-                  107,
-                  101,
-                  110
+                  114
                 ]
               },
               {
                 "kind": "arg",
-                "path": "paperId"
+                "path": "editor"
               }
             ]
           }
         },
         {
-          "name": "platformTokenAccount",
-          "writable": true,
+          "name": "programState",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  108,
-                  97,
-                  116,
-                  102,
+                  114,
                   111,
+                  103,
                   114,
+                  97,
                   109,
                   45,
-                  118,
+                  115,
+                  116,
                   97,
-                  117,
-                  108,
-                  116
+                  116,
+                  101
                 ]
               }
             ]
           }
         },
         {
-          "name": "funding",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "editor",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "addExternalCitation",
+      "docs": [
+        "Register a citation from the author's paper to an external work identified by DOI"
+      ],
+      "discriminator": [
+        27,
+        174,
+        157,
+        33,
+        79,
+        75,
+        41,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "author",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  102,
-                  117,
-                  110,
-                  100,
-                  105,
-                  110,
-                  103
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "paperId"
-              },
-              {
-                "kind": "account",
-                "path": "funder"
-              }
-            ]
-          }
+          "signer": true
+        },
+        {
+          "name": "citingPaper",
+          "writable": true
+        },
+        {
+          "name": "citation",
+          "writable": true
         },
         {
           "name": "programState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -280,10 +262,6 @@ export type BioxResearch = {
             ]
           }
         },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -291,34 +269,29 @@ export type BioxResearch = {
       ],
       "args": [
         {
-          "name": "paperId",
-          "type": "u64"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
+          "name": "doi",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "initialize",
+      "name": "cancelAdminProposal",
       "docs": [
-        "Initialize the program with an admin account"
+        "Cancel a pending admin proposal (admin only)"
       ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        68,
+        6,
+        145,
+        131,
+        16,
+        73,
+        182,
+        229
       ],
       "accounts": [
         {
           "name": "admin",
-          "writable": true,
           "signer": true
         },
         {
@@ -346,28 +319,24 @@ export type BioxResearch = {
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "publishPaper",
+      "name": "cancelPaper",
       "docs": [
-        "Publish a paper (by author) or approve (by admin)"
+        "Withdraw a paper that was never published (author only)"
       ],
       "discriminator": [
-        132,
-        116,
-        126,
-        224,
-        32,
-        207,
-        19,
-        18
+        170,
+        64,
+        4,
+        29,
+        83,
+        67,
+        241,
+        116
       ],
       "accounts": [
         {
@@ -376,38 +345,20 @@ export type BioxResearch = {
         },
         {
           "name": "paper",
-          "writable": true,
+          "writable": true
+        },
+        {
+          "name": "programState",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  97,
-                  112,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "paperId"
-              }
-            ]
-          }
-        },
-        {
-          "name": "programState",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  114,
-                  111,
-                  103,
-                  114,
+                  114,
+                  111,
+                  103,
+                  114,
                   97,
                   109,
                   45,
@@ -422,60 +373,30 @@ export type BioxResearch = {
           }
         }
       ],
-      "args": [
-        {
-          "name": "paperId",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "submitPaper",
+      "name": "cancelSettingsChange",
       "docs": [
-        "Submit a new research paper"
+        "Cancel a queued settings change (admin only)"
       ],
       "discriminator": [
-        50,
-        4,
-        72,
-        165,
-        234,
-        253,
-        22,
-        113
+        114,
+        170,
+        162,
+        28,
+        126,
+        102,
+        2,
+        145
       ],
       "accounts": [
         {
-          "name": "author",
-          "writable": true,
+          "name": "admin",
           "signer": true
         },
-        {
-          "name": "paper",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  97,
-                  112,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "program_state.paper_count",
-                "account": "programState"
-              }
-            ]
-          }
-        },
         {
           "name": "programState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -500,111 +421,63 @@ export type BioxResearch = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "abstractText",
-          "type": "string"
-        },
-        {
-          "name": "ipfsHash",
-          "type": "string"
-        },
-        {
-          "name": "authors",
-          "type": {
-            "vec": "string"
-          }
-        },
-        {
-          "name": "fundingGoal",
-          "type": "u64"
-        },
-        {
-          "name": "fundingPeriodDays",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "togglePause",
-      "docs": [
-        "Emergency pause (admin only)"
-      ],
-      "discriminator": [
-        238,
-        237,
-        206,
-        27,
-        255,
-        95,
-        123,
-        229
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "signer": true
-        },
-        {
-          "name": "programState",
+          "name": "settingsChange",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  103,
-                  114,
-                  97,
-                  109,
-                  45,
                   115,
+                  101,
                   116,
-                  97,
                   116,
+                  105,
+                  110,
+                  103,
+                  115,
+                  45,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
                   101
                 ]
               }
             ]
           }
+        },
+        {
+          "name": "proposer",
+          "writable": true
         }
       ],
       "args": []
     },
     {
-      "name": "updateSettings",
+      "name": "castGovernanceVote",
       "docs": [
-        "Update platform settings (admin only)"
+        "Vote on a proposal by escrowing BIOX until voting ends"
       ],
       "discriminator": [
-        81,
-        166,
-        51,
-        213,
-        158,
-        84,
         157,
-        108
+        100,
+        219,
+        247,
+        27,
+        45,
+        188,
+        41
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "voter",
+          "writable": true,
           "signer": true
         },
         {
           "name": "programState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -627,46 +500,9 @@ export type BioxResearch = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "platformFeeRate",
-          "type": {
-            "option": "u16"
-          }
-        },
-        {
-          "name": "minFundingGoal",
-          "type": {
-            "option": "u64"
-          }
-        }
-      ]
-    },
-    {
-      "name": "votePaper",
-      "docs": [
-        "Vote on a paper (with weighted voting)"
-      ],
-      "discriminator": [
-        227,
-        243,
-        208,
-        52,
-        166,
-        240,
-        1,
-        52
-      ],
-      "accounts": [
-        {
-          "name": "voter",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "paper",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
@@ -674,55 +510,74 @@ export type BioxResearch = {
                 "kind": "const",
                 "value": [
                   112,
-                  97,
+                  114,
+                  111,
                   112,
-                  101,
-                  114
+                  111,
+                  115,
+                  97,
+                  108
                 ]
               },
               {
                 "kind": "arg",
-                "path": "paperId"
+                "path": "proposalId"
               }
             ]
           }
         },
         {
-          "name": "programState",
+          "name": "voterTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "governanceVault",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
                   103,
+                  111,
+                  118,
+                  101,
                   114,
+                  110,
                   97,
-                  109,
+                  110,
+                  99,
+                  101,
                   45,
-                  115,
-                  116,
+                  118,
                   97,
-                  116,
-                  101
+                  117,
+                  108,
+                  116
                 ]
               }
             ]
           }
         },
         {
-          "name": "voterTokenAccount"
-        },
-        {
-          "name": "vote",
+          "name": "governanceVote",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  45,
                   118,
                   111,
                   116,
@@ -731,7 +586,7 @@ export type BioxResearch = {
               },
               {
                 "kind": "arg",
-                "path": "paperId"
+                "path": "proposalId"
               },
               {
                 "kind": "account",
@@ -740,6 +595,10 @@ export type BioxResearch = {
             ]
           }
         },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -747,239 +606,6391 @@ export type BioxResearch = {
       ],
       "args": [
         {
-          "name": "paperId",
+          "name": "proposalId",
           "type": "u64"
         },
         {
-          "name": "isUpvote",
+          "name": "support",
           "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "funding",
+      "name": "changeVote",
+      "docs": [
+        "Flip or re-cast an existing vote. Quadratic votes keep the weight their credits bought;",
+        "other votes are recomputed from the voter's current escrowed voting power."
+      ],
       "discriminator": [
-        50,
-        175,
-        214,
-        196,
-        200,
-        110,
-        145,
-        161
-      ]
-    },
-    {
-      "name": "programState",
-      "discriminator": [
-        77,
-        209,
+        184,
+        39,
+        97,
         137,
-        229,
-        149,
-        67,
-        167,
-        230
-      ]
-    },
-    {
-      "name": "researchPaper",
-      "discriminator": [
-        103,
-        125,
-        147,
-        192,
-        213,
-        248,
-        70,
-        245
+        83,
+        108,
+        185,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteEscrow",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  45,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vote",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "isUpvote",
+          "type": "bool"
+        }
       ]
     },
     {
-      "name": "vote",
+      "name": "claimFunds",
+      "docs": [
+        "Claim funds (only by author when fully funded)"
+      ],
       "discriminator": [
-        96,
-        91,
-        104,
-        57,
         145,
-        35,
-        172,
+        36,
+        143,
+        242,
+        168,
+        66,
+        200,
         155
-      ]
-    }
-  ],
-  "events": [
+      ],
+      "accounts": [
+        {
+          "name": "author",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "paperTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "authorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "authorProfile",
+          "docs": [
+            "Created for authors of papers from before profiles existed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  97,
+                  114,
+                  99,
+                  104,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper.author",
+                "account": "researchPaper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
     {
-      "name": "fundsClaimedEvent",
+      "name": "clearPauseFlags",
+      "docs": [
+        "Unpause one or more instruction families (admin only)"
+      ],
       "discriminator": [
-        214,
-        130,
-        38,
-        93,
-        0,
-        252,
-        245,
-        188
+        176,
+        86,
+        185,
+        138,
+        191,
+        49,
+        94,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        }
       ]
     },
     {
-      "name": "paperFundedEvent",
+      "name": "closeFunding",
+      "docs": [
+        "Close a funding record once its paper is settled, retracted after its funds were claimed,",
+        "or closed, returning rent to the funder"
+      ],
       "discriminator": [
-        101,
-        161,
         151,
-        40,
-        4,
-        35,
-        70,
-        86
-      ]
+        114,
+        204,
+        37,
+        205,
+        230,
+        237,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "funder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper"
+        },
+        {
+          "name": "funding",
+          "writable": true
+        },
+        {
+          "name": "retraction",
+          "docs": [
+            "Required while the paper exists and is retracted"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "paperPublishedEvent",
+      "name": "closePaper",
+      "docs": [
+        "Close a settled paper and its metadata (author only), returning rent to the author. The closing event",
+        "carries the paper's final tallies so its history stays reconstructable from logs.",
+        "Expired and retracted papers can be closed once every funder has been refunded, and papers",
+        "retracted after their funds were claimed at any time."
+      ],
       "discriminator": [
-        43,
-        91,
+        99,
+        67,
+        102,
         217,
-        125,
-        104,
-        147,
-        251,
-        7
-      ]
+        26,
+        149,
+        198,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "author",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  112,
+                  101,
+                  114,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "retraction",
+          "docs": [
+            "Required when the paper is retracted with funding outstanding"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "paperSubmittedEvent",
+      "name": "closeVote",
+      "docs": [
+        "Close a vote record once its paper is settled or retracted and voting has closed, or once",
+        "the paper is closed, returning rent to the voter. The vote's weight stays in the final",
+        "tally."
+      ],
       "discriminator": [
+        137,
+        152,
+        87,
+        249,
+        170,
         239,
-        163,
-        0,
-        214,
-        40,
-        247,
-        34,
-        204
-      ]
+        133,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper"
+        },
+        {
+          "name": "vote",
+          "writable": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "paperVotedEvent",
+      "name": "commitPriority",
+      "docs": [
+        "Commit to an unpublished manuscript without disclosing it. The commitment is",
+        "sha256(committer || manuscript CID digest || salt)."
+      ],
       "discriminator": [
-        2,
-        167,
-        208,
-        126,
-        167,
-        89,
-        84,
-        231
+        5,
+        159,
+        91,
+        18,
+        207,
+        230,
+        7,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "committer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "priorityCommitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "committer"
+              },
+              {
+                "kind": "arg",
+                "path": "commitment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
       ]
     },
     {
-      "name": "pauseToggledEvent",
+      "name": "createProposal",
+      "docs": [
+        "Create a governance proposal to change one platform parameter (BIOX holders)"
+      ],
       "discriminator": [
-        210,
-        185,
+        132,
+        116,
+        68,
+        174,
+        216,
+        160,
         198,
-        169,
-        200,
-        181,
-        119,
-        167
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidTitle",
-      "msg": "Invalid title length"
-    },
-    {
-      "code": 6001,
-      "name": "invalidAbstract",
-      "msg": "Invalid abstract length"
-    },
-    {
-      "code": 6002,
-      "name": "invalidIpfsHash",
-      "msg": "Invalid IPFS hash"
-    },
-    {
-      "code": 6003,
-      "name": "invalidAuthors",
-      "msg": "Invalid authors list"
-    },
-    {
-      "code": 6004,
-      "name": "fundingGoalTooLow",
-      "msg": "Funding goal too low"
-    },
-    {
-      "code": 6005,
-      "name": "invalidFundingPeriod",
-      "msg": "Invalid funding period"
-    },
-    {
-      "code": 6006,
-      "name": "unauthorized",
-      "msg": "Unauthorized action"
-    },
-    {
-      "code": 6007,
-      "name": "paperNotPublished",
-      "msg": "Paper is not published"
-    },
-    {
-      "code": 6008,
-      "name": "fundingDeadlinePassed",
-      "msg": "Funding deadline has passed"
-    },
-    {
-      "code": 6009,
-      "name": "invalidAmount",
-      "msg": "Invalid amount"
-    },
-    {
-      "code": 6010,
-      "name": "invalidPaperStatus",
-      "msg": "Invalid paper status"
-    },
+        22
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposerTokenAccount"
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "program_state.proposal_count",
+                "account": "programState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": {
+              "name": "parameterChange"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeProposal",
+      "docs": [
+        "Apply a proposal that met quorum and passed its threshold once voting has ended (anyone).",
+        "Unlike admin settings changes this skips the settings timelock: the proposal has been",
+        "public for its whole voting period, which is the notice the timelock exists to give."
+      ],
+      "discriminator": [
+        186,
+        60,
+        116,
+        133,
+        108,
+        128,
+        111,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeSettingsChange",
+      "docs": [
+        "Apply a queued settings change once its delay has elapsed (anyone)"
+      ],
+      "discriminator": [
+        122,
+        226,
+        186,
+        21,
+        162,
+        48,
+        166,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "settingsChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  105,
+                  110,
+                  103,
+                  115,
+                  45,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expirePaper",
+      "docs": [
+        "Expire a published paper whose funding deadline has passed, opening refunds to its funders.",
+        "Permissionless so that keepers can crank it."
+      ],
+      "discriminator": [
+        220,
+        225,
+        228,
+        94,
+        63,
+        196,
+        101,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "paper",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeVotes",
+      "docs": [
+        "Snapshot a paper's vote tally once its voting window has closed. Permissionless so that",
+        "keepers can crank it; the snapshot account can only be created once."
+      ],
+      "discriminator": [
+        128,
+        235,
+        61,
+        11,
+        90,
+        13,
+        194,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper"
+        },
+        {
+          "name": "voteTally",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  45,
+                  116,
+                  97,
+                  108,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundPaper",
+      "docs": [
+        "Fund a published paper"
+      ],
+      "discriminator": [
+        223,
+        208,
+        122,
+        79,
+        221,
+        8,
+        184,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "funder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "paperTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "funding",
+          "writable": true
+        },
+        {
+          "name": "authorProfile",
+          "docs": [
+            "Created for authors of papers from before profiles existed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  97,
+                  114,
+                  99,
+                  104,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper.author",
+                "account": "researchPaper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "docs": [
+        "Initialize the program with an admin account"
+      ],
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeGovernance",
+      "docs": [
+        "Enable token governance with the BIOX mint and its escrow vault, which also holds",
+        "vote-escrow locks (admin only, once)"
+      ],
+      "discriminator": [
+        171,
+        87,
+        101,
+        237,
+        27,
+        107,
+        201,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governanceMint"
+        },
+        {
+          "name": "governanceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "quorum",
+          "type": "u64"
+        },
+        {
+          "name": "thresholdBps",
+          "type": "u16"
+        },
+        {
+          "name": "votingPeriod",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "lockTokens",
+      "docs": [
+        "Lock BIOX in the governance vault until `now + duration` for decaying voting power.",
+        "Calling again adds to the lock and may extend, but never shorten, its unlock time."
+      ],
+      "discriminator": [
+        136,
+        11,
+        32,
+        232,
+        161,
+        117,
+        54,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  45,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "governanceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "duration",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "migrateProgramState",
+      "docs": [
+        "Upgrade the program state account to the current layout (admin only)"
+      ],
+      "discriminator": [
+        12,
+        91,
+        211,
+        39,
+        94,
+        44,
+        95,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governanceMint",
+          "docs": [
+            "Required to migrate from before version 3 once governance has been initialized"
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateResearchPaper",
+      "docs": [
+        "Upgrade a paper account to the current layout (author or admin), moving its strings",
+        "into a new metadata account"
+      ],
+      "discriminator": [
+        87,
+        253,
+        146,
+        93,
+        52,
+        115,
+        200,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  112,
+                  101,
+                  114,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateVote",
+      "docs": [
+        "Append the `credits` field to a vote cast before quadratic voting (voter only). Its",
+        "credits are zero, so it keeps its escrow-derived weight."
+      ],
+      "discriminator": [
+        253,
+        137,
+        67,
+        73,
+        220,
+        8,
+        109,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vote",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeAdmin",
+      "docs": [
+        "Propose a new admin (admin only). The proposed key must accept before it takes effect."
+      ],
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "publishPaper",
+      "docs": [
+        "Publish a paper (by author) or approve (by admin)"
+      ],
+      "discriminator": [
+        132,
+        116,
+        126,
+        224,
+        32,
+        207,
+        19,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "categoryIndex",
+          "writable": true
+        },
+        {
+          "name": "categoryPage",
+          "writable": true
+        },
+        {
+          "name": "authorProfile",
+          "docs": [
+            "Created for authors of papers from before profiles existed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  97,
+                  114,
+                  99,
+                  104,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper.author",
+                "account": "researchPaper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "publishRevision",
+      "docs": [
+        "Publish a new manuscript revision of a published paper (author only).",
+        "Version 1 is the CID stored on the paper itself."
+      ],
+      "discriminator": [
+        93,
+        217,
+        97,
+        169,
+        76,
+        73,
+        45,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "author",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "revision",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ipfsHash",
+          "type": "string"
+        },
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "changelogCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "queueSettingsChange",
+      "docs": [
+        "Queue a platform settings change (admin only). It takes effect once",
+        "`settings_delay` has elapsed and someone calls `execute_settings_change`."
+      ],
+      "discriminator": [
+        52,
+        62,
+        217,
+        51,
+        92,
+        163,
+        0,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "settingsChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  105,
+                  110,
+                  103,
+                  115,
+                  45,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "platformFeeRate",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "minFundingGoal",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "settingsDelay",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "voteWeight",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "voteWeightFormula"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "refundFunding",
+      "docs": [
+        "Return a funder's contribution from an expired or retracted paper and close their funding",
+        "record. The platform fee is not refunded, and neither are funds the author already claimed."
+      ],
+      "discriminator": [
+        124,
+        95,
+        21,
+        191,
+        47,
+        12,
+        105,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "funder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "funding",
+          "writable": true
+        },
+        {
+          "name": "paperTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "retraction",
+          "docs": [
+            "Required when the paper is retracted, to tell whether its funds were already claimed"
+          ],
+          "optional": true
+        },
+        {
+          "name": "authorProfile",
+          "docs": [
+            "Created for authors of papers from before profiles existed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  97,
+                  114,
+                  99,
+                  104,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper.author",
+                "account": "researchPaper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectPaper",
+      "docs": [
+        "Reject a paper under review (admin only)"
+      ],
+      "discriminator": [
+        79,
+        79,
+        58,
+        193,
+        142,
+        168,
+        51,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeEditor",
+      "docs": [
+        "Revoke the editor role (admin only)"
+      ],
+      "discriminator": [
+        237,
+        47,
+        97,
+        245,
+        133,
+        77,
+        245,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "editorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  100,
+                  105,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "editor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "editor",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "requestReview",
+      "docs": [
+        "Submit a draft paper for admin review instead of publishing it directly (author only)"
+      ],
+      "discriminator": [
+        188,
+        34,
+        192,
+        201,
+        180,
+        119,
+        132,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "retractPaper",
+      "docs": [
+        "Retract a published paper (author, admin or editor) with a public retraction notice"
+      ],
+      "discriminator": [
+        99,
+        118,
+        109,
+        157,
+        177,
+        233,
+        224,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "retraction",
+          "writable": true
+        },
+        {
+          "name": "editor",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  100,
+                  105,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "retractionReason"
+            }
+          }
+        },
+        {
+          "name": "noticeCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "retractVote",
+      "docs": [
+        "Withdraw a vote, removing its weight from the paper and returning rent to the voter"
+      ],
+      "discriminator": [
+        227,
+        0,
+        85,
+        234,
+        243,
+        42,
+        133,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vote",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revealPriority",
+      "docs": [
+        "Link a priority commitment to the committer's paper by revealing the salt"
+      ],
+      "discriminator": [
+        98,
+        0,
+        33,
+        239,
+        159,
+        107,
+        160,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "committer",
+          "signer": true
+        },
+        {
+          "name": "priorityCommitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "priority_commitment.committer",
+                "account": "priorityCommitment"
+              },
+              {
+                "kind": "account",
+                "path": "priority_commitment.commitment",
+                "account": "priorityCommitment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paper"
+        }
+      ],
+      "args": [
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPauseFlags",
+      "docs": [
+        "Pause one or more instruction families (admin only)"
+      ],
+      "discriminator": [
+        205,
+        167,
+        85,
+        237,
+        144,
+        202,
+        248,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setVotingWindow",
+      "docs": [
+        "Override a published paper's voting window (admin only). A window that has already",
+        "closed is final, so its tally snapshot cannot move, and no window may outlast a lock",
+        "that backs one of its votes."
+      ],
+      "discriminator": [
+        86,
+        227,
+        52,
+        19,
+        223,
+        141,
+        180,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "opensAt",
+          "type": "i64"
+        },
+        {
+          "name": "closesAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "submitPaper",
+      "docs": [
+        "Submit a new research paper"
+      ],
+      "discriminator": [
+        50,
+        4,
+        72,
+        165,
+        234,
+        253,
+        22,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "author",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authorIndex",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  45,
+                  105,
+                  110,
+                  100,
+                  101,
+                  120
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "author"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paper",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "author"
+              },
+              {
+                "kind": "account",
+                "path": "author_index.paper_count",
+                "account": "authorIndex"
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  112,
+                  101,
+                  114,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "manuscriptRegistry",
+          "writable": true
+        },
+        {
+          "name": "authorProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  97,
+                  114,
+                  99,
+                  104,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "author"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "abstractText",
+          "type": "string"
+        },
+        {
+          "name": "ipfsHash",
+          "type": "string"
+        },
+        {
+          "name": "authors",
+          "type": {
+            "vec": "string"
+          }
+        },
+        {
+          "name": "fundingGoal",
+          "type": "u64"
+        },
+        {
+          "name": "fundingPeriodDays",
+          "type": "u64"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "researchCategory"
+            }
+          }
+        },
+        {
+          "name": "keywords",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "togglePause",
+      "docs": [
+        "Emergency pause (admin only). Toggles every instruction family except",
+        "claims and refunds, so funded authors and funders are never trapped."
+      ],
+      "discriminator": [
+        238,
+        237,
+        206,
+        27,
+        255,
+        95,
+        123,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updatePaper",
+      "docs": [
+        "Edit a draft paper (author only), resizing its metadata account to fit"
+      ],
+      "discriminator": [
+        194,
+        186,
+        148,
+        8,
+        108,
+        44,
+        158,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "author",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  112,
+                  101,
+                  114,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "manuscriptRegistry",
+          "docs": [
+            "Required when `ipfs_hash` changes, to register the new manuscript"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "previousManuscriptRegistry",
+          "docs": [
+            "empty for papers migrated from before the registry"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  110,
+                  117,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "paper.content_id.digest",
+                "account": "researchPaper"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "abstractText",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "ipfsHash",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "authors",
+          "type": {
+            "option": {
+              "vec": "string"
+            }
+          }
+        },
+        {
+          "name": "keywords",
+          "type": {
+            "option": {
+              "vec": "string"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateProfile",
+      "docs": [
+        "Create or update the caller's researcher profile. Profiles are also created",
+        "automatically on first submission; stats are maintained by the program."
+      ],
+      "discriminator": [
+        98,
+        67,
+        99,
+        206,
+        86,
+        115,
+        175,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  97,
+                  114,
+                  99,
+                  104,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "displayName",
+          "type": "string"
+        },
+        {
+          "name": "affiliation",
+          "type": "string"
+        },
+        {
+          "name": "orcid",
+          "type": "string"
+        },
+        {
+          "name": "bioCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "votePaper",
+      "docs": [
+        "Vote on a paper. In linear mode the weight comes from the voter's vote-escrowed BIOX;",
+        "in quadratic mode the voter spends `credits` from the allowance that BIOX grants this",
+        "epoch, for a weight of their square root."
+      ],
+      "discriminator": [
+        227,
+        243,
+        208,
+        52,
+        166,
+        240,
+        1,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paper",
+          "writable": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteEscrow",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  45,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voterCredits",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  45,
+                  99,
+                  114,
+                  101,
+                  100,
+                  105,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              },
+              {
+                "kind": "account",
+                "path": "programState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vote",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "isUpvote",
+          "type": "bool"
+        },
+        {
+          "name": "credits",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawGovernanceVote",
+      "docs": [
+        "Return escrowed voting tokens once a proposal's voting period has ended"
+      ],
+      "discriminator": [
+        79,
+        251,
+        81,
+        167,
+        64,
+        67,
+        110,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "governanceVote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  45,
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voterTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "governanceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawLock",
+      "docs": [
+        "Return an expired lock's tokens and rent to its owner"
+      ],
+      "discriminator": [
+        81,
+        157,
+        253,
+        160,
+        94,
+        29,
+        90,
+        143
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  45,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "governanceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authorIndex",
+      "discriminator": [
+        142,
+        201,
+        180,
+        96,
+        182,
+        241,
+        213,
+        71
+      ]
+    },
+    {
+      "name": "categoryIndex",
+      "discriminator": [
+        174,
+        97,
+        185,
+        19,
+        253,
+        33,
+        128,
+        92
+      ]
+    },
+    {
+      "name": "categoryIndexPage",
+      "discriminator": [
+        205,
+        74,
+        197,
+        58,
+        158,
+        5,
+        247,
+        240
+      ]
+    },
+    {
+      "name": "citation",
+      "discriminator": [
+        110,
+        162,
+        132,
+        96,
+        24,
+        53,
+        78,
+        35
+      ]
+    },
+    {
+      "name": "editor",
+      "discriminator": [
+        90,
+        127,
+        78,
+        83,
+        228,
+        36,
+        239,
+        199
+      ]
+    },
+    {
+      "name": "funding",
+      "discriminator": [
+        50,
+        175,
+        214,
+        196,
+        200,
+        110,
+        145,
+        161
+      ]
+    },
+    {
+      "name": "governanceProposal",
+      "discriminator": [
+        53,
+        107,
+        240,
+        190,
+        43,
+        73,
+        65,
+        143
+      ]
+    },
+    {
+      "name": "governanceVote",
+      "discriminator": [
+        157,
+        104,
+        16,
+        111,
+        208,
+        31,
+        53,
+        132
+      ]
+    },
+    {
+      "name": "manuscriptRegistry",
+      "discriminator": [
+        18,
+        221,
+        152,
+        190,
+        1,
+        223,
+        93,
+        149
+      ]
+    },
+    {
+      "name": "paperMetadata",
+      "discriminator": [
+        110,
+        77,
+        162,
+        9,
+        168,
+        66,
+        193,
+        154
+      ]
+    },
+    {
+      "name": "paperRevision",
+      "discriminator": [
+        87,
+        107,
+        244,
+        228,
+        201,
+        59,
+        243,
+        179
+      ]
+    },
+    {
+      "name": "pendingSettingsChange",
+      "discriminator": [
+        40,
+        89,
+        32,
+        252,
+        57,
+        69,
+        145,
+        122
+      ]
+    },
+    {
+      "name": "priorityCommitment",
+      "discriminator": [
+        191,
+        121,
+        49,
+        69,
+        91,
+        154,
+        33,
+        38
+      ]
+    },
+    {
+      "name": "programState",
+      "discriminator": [
+        77,
+        209,
+        137,
+        229,
+        149,
+        67,
+        167,
+        230
+      ]
+    },
+    {
+      "name": "researchPaper",
+      "discriminator": [
+        103,
+        125,
+        147,
+        192,
+        213,
+        248,
+        70,
+        245
+      ]
+    },
+    {
+      "name": "researcherProfile",
+      "discriminator": [
+        23,
+        124,
+        179,
+        26,
+        47,
+        98,
+        66,
+        219
+      ]
+    },
+    {
+      "name": "retraction",
+      "discriminator": [
+        116,
+        193,
+        213,
+        195,
+        210,
+        167,
+        108,
+        4
+      ]
+    },
+    {
+      "name": "vote",
+      "discriminator": [
+        96,
+        91,
+        104,
+        57,
+        145,
+        35,
+        172,
+        155
+      ]
+    },
+    {
+      "name": "voteEscrow",
+      "discriminator": [
+        242,
+        251,
+        110,
+        92,
+        198,
+        238,
+        144,
+        106
+      ]
+    },
+    {
+      "name": "voteTally",
+      "discriminator": [
+        68,
+        102,
+        147,
+        82,
+        13,
+        184,
+        200,
+        176
+      ]
+    },
+    {
+      "name": "voterCredits",
+      "discriminator": [
+        96,
+        170,
+        66,
+        16,
+        88,
+        204,
+        127,
+        254
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountMigratedEvent",
+      "discriminator": [
+        109,
+        3,
+        25,
+        119,
+        155,
+        108,
+        69,
+        61
+      ]
+    },
+    {
+      "name": "adminAcceptedEvent",
+      "discriminator": [
+        14,
+        221,
+        60,
+        92,
+        241,
+        147,
+        95,
+        66
+      ]
+    },
+    {
+      "name": "adminProposalCancelledEvent",
+      "discriminator": [
+        149,
+        66,
+        174,
+        45,
+        14,
+        142,
+        15,
+        179
+      ]
+    },
+    {
+      "name": "adminProposedEvent",
+      "discriminator": [
+        212,
+        163,
+        91,
+        28,
+        223,
+        95,
+        2,
+        102
+      ]
+    },
+    {
+      "name": "citationAddedEvent",
+      "discriminator": [
+        156,
+        234,
+        76,
+        194,
+        23,
+        209,
+        165,
+        139
+      ]
+    },
+    {
+      "name": "editorAddedEvent",
+      "discriminator": [
+        121,
+        96,
+        49,
+        123,
+        169,
+        27,
+        172,
+        251
+      ]
+    },
+    {
+      "name": "editorRemovedEvent",
+      "discriminator": [
+        169,
+        188,
+        230,
+        189,
+        71,
+        167,
+        57,
+        94
+      ]
+    },
+    {
+      "name": "fundingClosedEvent",
+      "discriminator": [
+        251,
+        228,
+        157,
+        142,
+        3,
+        187,
+        100,
+        48
+      ]
+    },
+    {
+      "name": "fundingRefundedEvent",
+      "discriminator": [
+        163,
+        101,
+        247,
+        236,
+        149,
+        158,
+        215,
+        53
+      ]
+    },
+    {
+      "name": "fundsClaimedEvent",
+      "discriminator": [
+        214,
+        130,
+        38,
+        93,
+        0,
+        252,
+        245,
+        188
+      ]
+    },
+    {
+      "name": "governanceVoteCastEvent",
+      "discriminator": [
+        230,
+        28,
+        2,
+        222,
+        244,
+        180,
+        221,
+        110
+      ]
+    },
+    {
+      "name": "governanceVoteWithdrawnEvent",
+      "discriminator": [
+        190,
+        19,
+        146,
+        207,
+        0,
+        20,
+        163,
+        87
+      ]
+    },
+    {
+      "name": "lockWithdrawnEvent",
+      "discriminator": [
+        120,
+        208,
+        85,
+        224,
+        245,
+        251,
+        47,
+        221
+      ]
+    },
+    {
+      "name": "manuscriptRegisteredEvent",
+      "discriminator": [
+        209,
+        209,
+        153,
+        80,
+        208,
+        215,
+        195,
+        109
+      ]
+    },
+    {
+      "name": "paperClosedEvent",
+      "discriminator": [
+        232,
+        71,
+        190,
+        226,
+        151,
+        170,
+        231,
+        172
+      ]
+    },
+    {
+      "name": "paperFundedEvent",
+      "discriminator": [
+        101,
+        161,
+        151,
+        40,
+        4,
+        35,
+        70,
+        86
+      ]
+    },
+    {
+      "name": "paperPublishedEvent",
+      "discriminator": [
+        43,
+        91,
+        217,
+        125,
+        104,
+        147,
+        251,
+        7
+      ]
+    },
+    {
+      "name": "paperRetractedEvent",
+      "discriminator": [
+        252,
+        140,
+        200,
+        222,
+        236,
+        152,
+        127,
+        110
+      ]
+    },
+    {
+      "name": "paperRevisedEvent",
+      "discriminator": [
+        137,
+        146,
+        89,
+        156,
+        188,
+        111,
+        79,
+        251
+      ]
+    },
+    {
+      "name": "paperStatusChangedEvent",
+      "discriminator": [
+        238,
+        86,
+        231,
+        173,
+        45,
+        105,
+        49,
+        113
+      ]
+    },
+    {
+      "name": "paperSubmittedEvent",
+      "discriminator": [
+        239,
+        163,
+        0,
+        214,
+        40,
+        247,
+        34,
+        204
+      ]
+    },
+    {
+      "name": "paperUpdatedEvent",
+      "discriminator": [
+        40,
+        32,
+        163,
+        103,
+        36,
+        253,
+        235,
+        120
+      ]
+    },
+    {
+      "name": "paperVotedEvent",
+      "discriminator": [
+        2,
+        167,
+        208,
+        126,
+        167,
+        89,
+        84,
+        231
+      ]
+    },
+    {
+      "name": "pauseFlagChangedEvent",
+      "discriminator": [
+        27,
+        122,
+        232,
+        251,
+        120,
+        10,
+        95,
+        109
+      ]
+    },
+    {
+      "name": "priorityCommittedEvent",
+      "discriminator": [
+        185,
+        135,
+        237,
+        27,
+        56,
+        154,
+        166,
+        167
+      ]
+    },
+    {
+      "name": "priorityRevealedEvent",
+      "discriminator": [
+        238,
+        199,
+        146,
+        153,
+        32,
+        250,
+        243,
+        167
+      ]
+    },
+    {
+      "name": "profileUpdatedEvent",
+      "discriminator": [
+        241,
+        132,
+        13,
+        233,
+        46,
+        80,
+        12,
+        50
+      ]
+    },
+    {
+      "name": "proposalCreatedEvent",
+      "discriminator": [
+        154,
+        240,
+        33,
+        66,
+        194,
+        233,
+        203,
+        209
+      ]
+    },
+    {
+      "name": "proposalExecutedEvent",
+      "discriminator": [
+        120,
+        242,
+        13,
+        36,
+        223,
+        3,
+        110,
+        180
+      ]
+    },
+    {
+      "name": "settingsChangeCancelledEvent",
+      "discriminator": [
+        112,
+        161,
+        38,
+        91,
+        227,
+        241,
+        52,
+        225
+      ]
+    },
+    {
+      "name": "settingsChangeExecutedEvent",
+      "discriminator": [
+        253,
+        139,
+        85,
+        95,
+        138,
+        76,
+        85,
+        175
+      ]
+    },
+    {
+      "name": "settingsChangeQueuedEvent",
+      "discriminator": [
+        250,
+        113,
+        165,
+        242,
+        8,
+        86,
+        8,
+        22
+      ]
+    },
+    {
+      "name": "tokensLockedEvent",
+      "discriminator": [
+        38,
+        108,
+        105,
+        31,
+        112,
+        12,
+        58,
+        244
+      ]
+    },
+    {
+      "name": "voteChangedEvent",
+      "discriminator": [
+        43,
+        204,
+        80,
+        151,
+        79,
+        32,
+        21,
+        143
+      ]
+    },
+    {
+      "name": "voteClosedEvent",
+      "discriminator": [
+        69,
+        31,
+        24,
+        14,
+        192,
+        72,
+        215,
+        8
+      ]
+    },
+    {
+      "name": "voteRetractedEvent",
+      "discriminator": [
+        187,
+        49,
+        177,
+        49,
+        236,
+        215,
+        218,
+        19
+      ]
+    },
+    {
+      "name": "voteTallyFinalizedEvent",
+      "discriminator": [
+        124,
+        80,
+        42,
+        177,
+        55,
+        104,
+        194,
+        197
+      ]
+    },
+    {
+      "name": "votingWindowChangedEvent",
+      "discriminator": [
+        98,
+        203,
+        68,
+        30,
+        203,
+        4,
+        182,
+        169
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidTitle",
+      "msg": "Invalid title length"
+    },
+    {
+      "code": 6001,
+      "name": "invalidAbstract",
+      "msg": "Invalid abstract length"
+    },
+    {
+      "code": 6002,
+      "name": "invalidIpfsHash",
+      "msg": "Invalid IPFS hash"
+    },
+    {
+      "code": 6003,
+      "name": "invalidAuthors",
+      "msg": "Invalid authors list"
+    },
+    {
+      "code": 6004,
+      "name": "fundingGoalTooLow",
+      "msg": "Funding goal too low"
+    },
+    {
+      "code": 6005,
+      "name": "invalidFundingPeriod",
+      "msg": "Invalid funding period"
+    },
+    {
+      "code": 6006,
+      "name": "unauthorized",
+      "msg": "Unauthorized action"
+    },
+    {
+      "code": 6007,
+      "name": "paperNotPublished",
+      "msg": "Paper is not published"
+    },
+    {
+      "code": 6008,
+      "name": "fundingDeadlinePassed",
+      "msg": "Funding deadline has passed"
+    },
+    {
+      "code": 6009,
+      "name": "invalidAmount",
+      "msg": "Invalid amount"
+    },
+    {
+      "code": 6010,
+      "name": "invalidPaperStatus",
+      "msg": "Invalid paper status"
+    },
+    {
+      "code": 6011,
+      "name": "notFullyFunded",
+      "msg": "Paper not fully funded"
+    },
+    {
+      "code": 6012,
+      "name": "noFundsToClam",
+      "msg": "No funds to claim"
+    },
+    {
+      "code": 6013,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6014,
+      "name": "feeTooHigh",
+      "msg": "Fee rate too high"
+    },
+    {
+      "code": 6015,
+      "name": "invalidAdmin",
+      "msg": "Invalid admin"
+    },
+    {
+      "code": 6016,
+      "name": "noPendingAdmin",
+      "msg": "No pending admin proposal"
+    },
+    {
+      "code": 6017,
+      "name": "emptySettingsChange",
+      "msg": "Settings change has no fields set"
+    },
+    {
+      "code": 6018,
+      "name": "invalidSettingsDelay",
+      "msg": "Invalid settings delay"
+    },
+    {
+      "code": 6019,
+      "name": "settingsChangeNotReady",
+      "msg": "Settings change delay has not elapsed"
+    },
+    {
+      "code": 6020,
+      "name": "invalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6021,
+      "name": "governanceNotInitialized",
+      "msg": "Governance is not initialized"
+    },
+    {
+      "code": 6022,
+      "name": "governanceAlreadyInitialized",
+      "msg": "Governance is already initialized"
+    },
+    {
+      "code": 6023,
+      "name": "insufficientGovernanceTokens",
+      "msg": "Insufficient governance tokens"
+    },
+    {
+      "code": 6024,
+      "name": "invalidParameterValue",
+      "msg": "Invalid parameter value"
+    },
+    {
+      "code": 6025,
+      "name": "proposalVotingClosed",
+      "msg": "Proposal voting has closed"
+    },
+    {
+      "code": 6026,
+      "name": "proposalVotingActive",
+      "msg": "Proposal voting is still active"
+    },
+    {
+      "code": 6027,
+      "name": "proposalAlreadyExecuted",
+      "msg": "Proposal already executed"
+    },
+    {
+      "code": 6028,
+      "name": "quorumNotReached",
+      "msg": "Quorum not reached"
+    },
+    {
+      "code": 6029,
+      "name": "proposalNotPassed",
+      "msg": "Proposal did not pass"
+    },
+    {
+      "code": 6030,
+      "name": "alreadyMigrated",
+      "msg": "Account is already at the current version"
+    },
+    {
+      "code": 6031,
+      "name": "emptyPaperUpdate",
+      "msg": "Paper update has no fields set"
+    },
+    {
+      "code": 6032,
+      "name": "paperRetracted",
+      "msg": "Paper has been retracted"
+    },
+    {
+      "code": 6033,
+      "name": "invalidCitation",
+      "msg": "Invalid citation"
+    },
+    {
+      "code": 6034,
+      "name": "invalidKeywords",
+      "msg": "Invalid keywords"
+    },
+    {
+      "code": 6035,
+      "name": "invalidDisplayName",
+      "msg": "Invalid display name"
+    },
+    {
+      "code": 6036,
+      "name": "invalidAffiliation",
+      "msg": "Invalid affiliation"
+    },
+    {
+      "code": 6037,
+      "name": "invalidOrcid",
+      "msg": "Invalid ORCID iD"
+    },
+    {
+      "code": 6038,
+      "name": "invalidContentId",
+      "msg": "Invalid content identifier"
+    },
+    {
+      "code": 6039,
+      "name": "duplicateManuscript",
+      "msg": "Manuscript has already been submitted"
+    },
+    {
+      "code": 6040,
+      "name": "manuscriptRegistryRequired",
+      "msg": "Manuscript registry account is required"
+    },
+    {
+      "code": 6041,
+      "name": "invalidCommitmentReveal",
+      "msg": "Commitment does not match the revealed manuscript"
+    },
+    {
+      "code": 6042,
+      "name": "commitmentAlreadyRevealed",
+      "msg": "Commitment has already been revealed"
+    },
+    {
+      "code": 6043,
+      "name": "invalidTransition",
+      "msg": "Paper status transition is not allowed"
+    },
+    {
+      "code": 6044,
+      "name": "fundingDeadlineNotPassed",
+      "msg": "Funding deadline has not passed"
+    },
+    {
+      "code": 6045,
+      "name": "invalidLockDuration",
+      "msg": "Lock duration must be positive, at most the maximum, and not shorten an existing lock"
+    },
+    {
+      "code": 6046,
+      "name": "lockNotExpired",
+      "msg": "Tokens are still locked"
+    },
+    {
+      "code": 6047,
+      "name": "voteCreditsRequired",
+      "msg": "Voter credits account is required in quadratic voting mode"
+    },
+    {
+      "code": 6048,
+      "name": "insufficientVoteCredits",
+      "msg": "Not enough vote credits left this epoch"
+    },
+    {
+      "code": 6049,
+      "name": "governanceMintRequired",
+      "msg": "Governance mint account is required"
+    },
+    {
+      "code": 6050,
+      "name": "votingNotOpen",
+      "msg": "Voting has not opened for this paper"
+    },
+    {
+      "code": 6051,
+      "name": "votingClosed",
+      "msg": "Voting has closed for this paper"
+    },
+    {
+      "code": 6052,
+      "name": "votingNotClosed",
+      "msg": "Voting has not closed for this paper"
+    },
+    {
+      "code": 6053,
+      "name": "invalidVotingWindow",
+      "msg": "Voting window must open before it closes and close in the future"
+    },
+    {
+      "code": 6054,
+      "name": "lockTooShort",
+      "msg": "Vote-escrow lock ends before the paper's voting window closes"
+    },
+    {
+      "code": 6055,
+      "name": "retractionRequired",
+      "msg": "Retraction account is required for a retracted paper"
+    },
+    {
+      "code": 6056,
+      "name": "fundsAlreadyClaimed",
+      "msg": "Paper funds were already claimed by its author"
+    },
+    {
+      "code": 6057,
+      "name": "manuscriptRequired",
+      "msg": "Paper has no manuscript CID; set one with update_paper"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigratedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminAcceptedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposalCancelledEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "currentAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposedAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorIndex",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "paperCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "categoryIndex",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "researchCategory"
+              }
+            }
+          },
+          {
+            "name": "paperCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "categoryIndexPage",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "researchCategory"
+              }
+            }
+          },
+          {
+            "name": "page",
+            "type": "u32"
+          },
+          {
+            "name": "papers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "citation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "citingPaperId",
+            "type": "u64"
+          },
+          {
+            "name": "target",
+            "type": {
+              "defined": {
+                "name": "citationTarget"
+              }
+            }
+          },
+          {
+            "name": "createdBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "citationAddedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "citingPaperId",
+            "type": "u64"
+          },
+          {
+            "name": "target",
+            "type": {
+              "defined": {
+                "name": "citationTarget"
+              }
+            }
+          },
+          {
+            "name": "citationsOut",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "citationTarget",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "paper",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "doi",
+            "fields": [
+              "string"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "codec",
+            "type": "u32"
+          },
+          {
+            "name": "hashCode",
+            "type": "u32"
+          },
+          {
+            "name": "digest",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "editor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "editor",
+            "type": "pubkey"
+          },
+          {
+            "name": "addedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "addedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "editorAddedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "editor",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "editorRemovedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "editor",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "funding",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fundingClosedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          },
+          {
+            "name": "fundedAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fundingRefundedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "remainingFunding",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fundsClaimedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "parameterChange"
+              }
+            }
+          },
+          {
+            "name": "votesFor",
+            "type": "u64"
+          },
+          {
+            "name": "votesAgainst",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "votingEndsAt",
+            "type": "i64"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceVote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "name": "support",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceVoteCastEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "name": "support",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceVoteWithdrawnEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lockWithdrawnEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manuscriptRegisteredEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
+          {
+            "name": "submitter",
+            "type": "pubkey"
+          },
+          {
+            "name": "digest",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manuscriptRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contentId",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
+          {
+            "name": "submitter",
+            "type": "pubkey"
+          },
+          {
+            "name": "submittedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperClosedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "paperStatus"
+              }
+            }
+          },
+          {
+            "name": "contentId",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "manuscriptVersion",
+            "type": "u32"
+          },
+          {
+            "name": "fundingGoal",
+            "type": "u64"
+          },
+          {
+            "name": "fundingCurrent",
+            "type": "u64"
+          },
+          {
+            "name": "upvotes",
+            "type": "u64"
+          },
+          {
+            "name": "downvotes",
+            "type": "u64"
+          },
+          {
+            "name": "citationsIn",
+            "type": "u32"
+          },
+          {
+            "name": "citationsOut",
+            "type": "u32"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperFundedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          },
+          {
+            "name": "totalFunding",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperMetadata",
+      "docs": [
+        "Descriptive paper fields, read by clients and written only while the paper is a draft"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "abstractText",
+            "type": "string"
+          },
+          {
+            "name": "authors",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "keywords",
+            "type": {
+              "vec": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperPublishedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "votingClosesAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperRetractedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "retractedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "retractionReason"
+              }
+            }
+          },
+          {
+            "name": "noticeCid",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperRevisedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u32"
+          },
+          {
+            "name": "contentId",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "changelogCid",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperRevision",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u32"
+          },
+          {
+            "name": "contentId",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "changelogCid",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "draft"
+          },
+          {
+            "name": "published"
+          },
+          {
+            "name": "fullyFunded"
+          },
+          {
+            "name": "completed"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "retracted"
+          },
+          {
+            "name": "underReview"
+          },
+          {
+            "name": "expired"
+          },
+          {
+            "name": "cancelled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperStatusChangedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "previous",
+            "type": {
+              "defined": {
+                "name": "paperStatus"
+              }
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "paperStatus"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperSubmittedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paperVotedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "name": "isUpvote",
+            "type": "bool"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "credits",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "parameterChange",
+      "docs": [
+        "A single platform parameter that a governance proposal can change"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "platformFeeRate",
+            "fields": [
+              "u16"
+            ]
+          },
+          {
+            "name": "minFundingGoal",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "maxFundingPeriod",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "voteWeightDivisor",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "voteWeightCap",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "governanceQuorum",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "governanceThreshold",
+            "fields": [
+              "u16"
+            ]
+          },
+          {
+            "name": "governanceVotingPeriod",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "votingMode",
+            "fields": [
+              {
+                "defined": {
+                  "name": "votingMode"
+                }
+              }
+            ]
+          },
+          {
+            "name": "voteCreditsPerEpoch",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "voteEpochDuration",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "voteWeightZeroBalance",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "voteWeightCurve",
+            "fields": [
+              {
+                "defined": {
+                  "name": "weightCurve"
+                }
+              }
+            ]
+          },
+          {
+            "name": "votingWindowDuration",
+            "fields": [
+              "i64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseFlagChangedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flag",
+            "type": "u8"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingSettingsChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "platformFeeRate",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "minFundingGoal",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "settingsDelay",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "voteWeight",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "voteWeightFormula"
+                }
+              }
+            }
+          },
+          {
+            "name": "proposedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "effectiveAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priorityCommitment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "committer",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "committedAt",
+            "type": "i64"
+          },
+          {
+            "name": "paper",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "revealedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priorityCommittedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "committer",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priorityRevealedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "committer",
+            "type": "pubkey"
+          },
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "committedAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "profileUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "paperCount",
+            "type": "u64"
+          },
+          {
+            "name": "totalFunding",
+            "type": "u64"
+          },
+          {
+            "name": "platformFeeRate",
+            "type": "u16"
+          },
+          {
+            "name": "minFundingGoal",
+            "type": "u64"
+          },
+          {
+            "name": "maxFundingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "settingsDelay",
+            "type": "i64"
+          },
+          {
+            "name": "voteWeightDivisor",
+            "type": "u64"
+          },
+          {
+            "name": "voteWeightCap",
+            "type": "u64"
+          },
+          {
+            "name": "governanceMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "governanceQuorum",
+            "type": "u64"
+          },
+          {
+            "name": "governanceThresholdBps",
+            "type": "u16"
+          },
+          {
+            "name": "governanceVotingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "votingMode",
+            "type": {
+              "defined": {
+                "name": "votingMode"
+              }
+            }
+          },
+          {
+            "name": "voteCreditsPerEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "voteEpochDuration",
+            "type": "i64"
+          },
+          {
+            "name": "governanceDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "voteWeightZeroBalance",
+            "type": "u64"
+          },
+          {
+            "name": "voteWeightCurve",
+            "type": {
+              "defined": {
+                "name": "weightCurve"
+              }
+            }
+          },
+          {
+            "name": "votingWindowDuration",
+            "type": "i64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                29
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalCreatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "parameterChange"
+              }
+            }
+          },
+          {
+            "name": "votingEndsAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalExecutedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "parameterChange"
+              }
+            }
+          },
+          {
+            "name": "votesFor",
+            "type": "u64"
+          },
+          {
+            "name": "votesAgainst",
+            "type": "u64"
+          },
+          {
+            "name": "executedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "researchCategory",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "genomics"
+          },
+          {
+            "name": "neuroscience"
+          },
+          {
+            "name": "oncology"
+          },
+          {
+            "name": "immunology"
+          },
+          {
+            "name": "microbiology"
+          },
+          {
+            "name": "bioinformatics"
+          },
+          {
+            "name": "pharmacology"
+          },
+          {
+            "name": "epidemiology"
+          },
+          {
+            "name": "syntheticBiology"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "researchPaper",
+      "docs": [
+        "Fixed-size paper state touched by funding, voting and status changes. The variable-length",
+        "strings live in `PaperMetadata` so the hot paths never decode them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "author",
+            "type": "pubkey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "fundingGoal",
+            "type": "u64"
+          },
+          {
+            "name": "fundingCurrent",
+            "type": "u64"
+          },
+          {
+            "name": "fundingDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "upvotes",
+            "type": "u64"
+          },
+          {
+            "name": "downvotes",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "paperStatus"
+              }
+            }
+          },
+          {
+            "name": "reviewScore",
+            "type": "u32"
+          },
+          {
+            "name": "reviewCount",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "manuscriptVersion",
+            "type": "u32"
+          },
+          {
+            "name": "citationsOut",
+            "type": "u32"
+          },
+          {
+            "name": "citationsIn",
+            "type": "u32"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "researchCategory"
+              }
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "contentId",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "votingOpensAt",
+            "type": "i64"
+          },
+          {
+            "name": "votingClosesAt",
+            "type": "i64"
+          },
+          {
+            "name": "legacySeeds",
+            "type": "bool"
+          },
+          {
+            "name": "earliestUnlockAt",
+            "type": "i64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                23
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
-      "code": 6011,
-      "name": "notFullyFunded",
-      "msg": "Paper not fully funded"
+      "name": "researcherProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "affiliation",
+            "type": "string"
+          },
+          {
+            "name": "orcid",
+            "type": "string"
+          },
+          {
+            "name": "bioCid",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "contentId"
+                }
+              }
+            }
+          },
+          {
+            "name": "papersSubmitted",
+            "type": "u32"
+          },
+          {
+            "name": "papersPublished",
+            "type": "u32"
+          },
+          {
+            "name": "papersCompleted",
+            "type": "u32"
+          },
+          {
+            "name": "totalFundingRaised",
+            "type": "u64"
+          },
+          {
+            "name": "reviewsDone",
+            "type": "u32"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "noFundsToClam",
-      "msg": "No funds to claim"
+      "name": "retraction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paperId",
+            "type": "u64"
+          },
+          {
+            "name": "retractedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "retractionReason"
+              }
+            }
+          },
+          {
+            "name": "noticeCid",
+            "type": {
+              "defined": {
+                "name": "contentId"
+              }
+            }
+          },
+          {
+            "name": "previousStatus",
+            "type": {
+              "defined": {
+                "name": "paperStatus"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "programPaused",
-      "msg": "Program is paused"
+      "name": "retractionReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "authorRequest"
+          },
+          {
+            "name": "misconduct"
+          },
+          {
+            "name": "dataError"
+          },
+          {
+            "name": "plagiarism"
+          },
+          {
+            "name": "ethicalConcerns"
+          },
+          {
+            "name": "duplicate"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "settingsChangeCancelledEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "settingsChangeExecutedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "platformFeeRate",
+            "type": "u16"
+          },
+          {
+            "name": "minFundingGoal",
+            "type": "u64"
+          },
+          {
+            "name": "settingsDelay",
+            "type": "i64"
+          },
+          {
+            "name": "voteWeight",
+            "type": {
+              "defined": {
+                "name": "voteWeightFormula"
+              }
+            }
+          },
+          {
+            "name": "executedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "settingsChangeQueuedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "platformFeeRate",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "minFundingGoal",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "settingsDelay",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "voteWeight",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "voteWeightFormula"
+                }
+              }
+            }
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "effectiveAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "feeTooHigh",
-      "msg": "Fee rate too high"
-    }
-  ],
-  "types": [
-    {
-      "name": "funding",
+      "name": "tokensLockedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "paperId",
-            "type": "u64"
-          },
-          {
-            "name": "funder",
+            "name": "owner",
             "type": "pubkey"
           },
           {
@@ -987,22 +6998,26 @@ export type BioxResearch = {
             "type": "u64"
           },
           {
-            "name": "platformFee",
+            "name": "totalLocked",
             "type": "u64"
           },
           {
-            "name": "timestamp",
+            "name": "unlockAt",
             "type": "i64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "votingPower",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "fundsClaimedEvent",
+      "name": "vote",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1011,22 +7026,34 @@ export type BioxResearch = {
             "type": "u64"
           },
           {
-            "name": "author",
+            "name": "voter",
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "isUpvote",
+            "type": "bool"
+          },
+          {
+            "name": "weight",
             "type": "u64"
           },
           {
             "name": "timestamp",
             "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "credits",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "paperFundedEvent",
+      "name": "voteChangedEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1035,19 +7062,23 @@ export type BioxResearch = {
             "type": "u64"
           },
           {
-            "name": "funder",
+            "name": "voter",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "previousIsUpvote",
+            "type": "bool"
           },
           {
-            "name": "platformFee",
+            "name": "previousWeight",
             "type": "u64"
           },
           {
-            "name": "totalFunding",
+            "name": "isUpvote",
+            "type": "bool"
+          },
+          {
+            "name": "weight",
             "type": "u64"
           },
           {
@@ -1058,74 +7089,74 @@ export type BioxResearch = {
       }
     },
     {
-      "name": "paperPublishedEvent",
+      "name": "voteClosedEvent",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "paper",
+            "type": "pubkey"
+          },
           {
             "name": "paperId",
             "type": "u64"
           },
           {
-            "name": "author",
+            "name": "voter",
             "type": "pubkey"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "paperStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "draft"
-          },
-          {
-            "name": "published"
+            "name": "isUpvote",
+            "type": "bool"
           },
           {
-            "name": "fullyFunded"
+            "name": "weight",
+            "type": "u64"
           },
           {
-            "name": "completed"
+            "name": "votedAt",
+            "type": "i64"
           },
           {
-            "name": "rejected"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "paperSubmittedEvent",
+      "name": "voteEscrow",
+      "docs": [
+        "BIOX locked in the governance vault; voting power decays linearly to zero at `unlock_at`"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "paperId",
-            "type": "u64"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "author",
-            "type": "pubkey"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "title",
-            "type": "string"
+            "name": "lockedAt",
+            "type": "i64"
           },
           {
-            "name": "timestamp",
+            "name": "unlockAt",
             "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "paperVotedEvent",
+      "name": "voteRetractedEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1153,53 +7184,40 @@ export type BioxResearch = {
       }
     },
     {
-      "name": "pauseToggledEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "isPaused",
-            "type": "bool"
-          },
-          {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "programState",
+      "name": "voteTally",
+      "docs": [
+        "A paper's vote tally frozen when its voting window closed"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "paper",
             "type": "pubkey"
           },
           {
-            "name": "paperCount",
+            "name": "paperId",
             "type": "u64"
           },
           {
-            "name": "totalFunding",
+            "name": "upvotes",
             "type": "u64"
           },
           {
-            "name": "platformFeeRate",
-            "type": "u16"
+            "name": "downvotes",
+            "type": "u64"
           },
           {
-            "name": "minFundingGoal",
-            "type": "u64"
+            "name": "votingOpensAt",
+            "type": "i64"
           },
           {
-            "name": "maxFundingPeriod",
+            "name": "votingClosesAt",
             "type": "i64"
           },
           {
-            "name": "isPaused",
-            "type": "bool"
+            "name": "finalizedAt",
+            "type": "i64"
           },
           {
             "name": "bump",
@@ -1209,83 +7227,87 @@ export type BioxResearch = {
       }
     },
     {
-      "name": "researchPaper",
+      "name": "voteTallyFinalizedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
+            "name": "paperId",
             "type": "u64"
           },
           {
-            "name": "author",
-            "type": "pubkey"
-          },
-          {
-            "name": "title",
-            "type": "string"
-          },
-          {
-            "name": "abstractText",
-            "type": "string"
-          },
-          {
-            "name": "ipfsHash",
-            "type": "string"
+            "name": "upvotes",
+            "type": "u64"
           },
           {
-            "name": "authors",
-            "type": {
-              "vec": "string"
-            }
+            "name": "downvotes",
+            "type": "u64"
           },
           {
-            "name": "createdAt",
+            "name": "votingOpensAt",
             "type": "i64"
           },
           {
-            "name": "updatedAt",
+            "name": "votingClosesAt",
             "type": "i64"
           },
           {
-            "name": "isPublished",
-            "type": "bool"
-          },
-          {
-            "name": "fundingGoal",
-            "type": "u64"
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteWeightFormula",
+      "docs": [
+        "The admin-editable inputs to `ProgramState::vote_weight`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "fundingCurrent",
+            "name": "divisor",
             "type": "u64"
           },
           {
-            "name": "fundingDeadline",
-            "type": "i64"
-          },
-          {
-            "name": "upvotes",
+            "name": "cap",
             "type": "u64"
           },
           {
-            "name": "downvotes",
+            "name": "zeroBalanceWeight",
             "type": "u64"
           },
           {
-            "name": "status",
+            "name": "curve",
             "type": {
               "defined": {
-                "name": "paperStatus"
+                "name": "weightCurve"
               }
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterCredits",
+      "docs": [
+        "Quadratic-voting credits a voter has spent in one epoch"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voter",
+            "type": "pubkey"
           },
           {
-            "name": "reviewScore",
-            "type": "u32"
+            "name": "epoch",
+            "type": "u64"
           },
           {
-            "name": "reviewCount",
-            "type": "u32"
+            "name": "spent",
+            "type": "u64"
           },
           {
             "name": "bump",
@@ -1295,7 +7317,24 @@ export type BioxResearch = {
       }
     },
     {
-      "name": "vote",
+      "name": "votingMode",
+      "docs": [
+        "How paper vote weight is computed"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "linear"
+          },
+          {
+            "name": "quadratic"
+          }
+        ]
+      }
+    },
+    {
+      "name": "votingWindowChangedEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1304,24 +7343,36 @@ export type BioxResearch = {
             "type": "u64"
           },
           {
-            "name": "voter",
-            "type": "pubkey"
-          },
-          {
-            "name": "isUpvote",
-            "type": "bool"
+            "name": "opensAt",
+            "type": "i64"
           },
           {
-            "name": "weight",
-            "type": "u64"
+            "name": "closesAt",
+            "type": "i64"
           },
           {
             "name": "timestamp",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "weightCurve",
+      "docs": [
+        "Shape of vote weight as voting power grows"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "linear"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "squareRoot"
+          },
+          {
+            "name": "logarithmic"
           }
         ]
       }
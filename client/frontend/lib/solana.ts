@@ -157,16 +157,29 @@ export function getProgramStatePda() {
   );
 }
 
-export function getPaperPda(paperId: anchor.BN) {
+export function getPaperPda(author: PublicKey, nonce: anchor.BN) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("paper"), author.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+    PROGRAM_ID
+  );
+}
+
+// Papers migrated from before per-author addressing are still keyed by their global id
+export function getLegacyPaperPda(paperId: anchor.BN) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("paper"), paperId.toArrayLike(Buffer, "le", 8)],
     PROGRAM_ID
   );
 }
 
-export function getPaperTokenAccountPda(paperId: anchor.BN) {
+// Seed of a paper's escrow, funding and vote accounts: its id for legacy papers, otherwise its address
+export function getPaperChildSeed(paperPda: PublicKey, paper: { id: anchor.BN; legacySeeds: boolean }) {
+  return paper.legacySeeds ? paper.id.toArrayLike(Buffer, "le", 8) : paperPda.toBuffer();
+}
+
+export function getPaperTokenAccountPda(childSeed: Buffer) {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("paper-token"), paperId.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from("paper-token"), childSeed],
     PROGRAM_ID
   );
 }
@@ -178,20 +191,55 @@ export function getPlatformVaultPda() {
   );
 }
 
-export function getFundingPda(paperId: anchor.BN, funder: PublicKey) {
+export function getFundingPda(childSeed: Buffer, funder: PublicKey) {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("funding"), paperId.toArrayLike(Buffer, "le", 8), funder.toBuffer()],
+    [Buffer.from("funding"), childSeed, funder.toBuffer()],
     PROGRAM_ID
   );
 }
 
-export function getVotePda(paperId: anchor.BN, voter: PublicKey) {
+export function getVotePda(childSeed: Buffer, voter: PublicKey) {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("vote"), paperId.toArrayLike(Buffer, "le", 8), voter.toBuffer()],
+    [Buffer.from("vote"), childSeed, voter.toBuffer()],
     PROGRAM_ID
   );
 }
 
+// Paper addresses no longer follow from their id, so look the paper up by the id it stores
+export async function findPaper(program: anchor.Program<BioxResearch>, paperId: anchor.BN) {
+  const [paper] = await program.account.researchPaper.all([
+    { memcmp: { offset: 8, bytes: anchor.utils.bytes.bs58.encode(paperId.toArrayLike(Buffer, "le", 8)) } },
+  ]);
+  if (!paper) {
+    throw new Error(`Paper ${paperId.toString()} not found`);
+  }
+  return {
+    paperPda: paper.publicKey,
+    paper: paper.account,
+    childSeed: getPaperChildSeed(paper.publicKey, paper.account),
+  };
+}
+
+// Index and current page a paper is listed under when it is published
+export async function getCategoryPdas(program: anchor.Program<BioxResearch>, category: object) {
+  const categories = (idl.types.find((type) => type.name === "ResearchCategory")!.type as {
+    variants: { name: string }[];
+  }).variants;
+  const variant = Object.keys(category)[0].toLowerCase();
+  const categoryByte = Buffer.from([categories.findIndex((v) => v.name.toLowerCase() === variant)]);
+  const [categoryIndexPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("category"), categoryByte],
+    PROGRAM_ID
+  );
+  const categoryIndex = await program.account.categoryIndex.fetchNullable(categoryIndexPda);
+  const page = categoryIndex ? categoryIndex.paperCount.divn(64).toNumber() : 0;
+  const [categoryPagePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("category-page"), categoryByte, new anchor.BN(page).toArrayLike(Buffer, "le", 4)],
+    PROGRAM_ID
+  );
+  return { categoryIndexPda, categoryPagePda };
+}
+
 // OPTIMAL: Check and initialize program state if needed
 export async function ensureProgramInitialized(
   program: anchor.Program<BioxResearch>,
@@ -324,10 +372,7 @@ export async function getPaperDetails(
   paperId: number
 ) {
   try {
-    const paperIdBN = new anchor.BN(paperId);
-    const [paperPda] = getPaperPda(paperIdBN);
-    
-    const paper = await program.account.researchPaper.fetch(paperPda);
+    const { paperPda, paper } = await findPaper(program, new anchor.BN(paperId));
     
     return {
       success: true,
@@ -364,11 +409,11 @@ export async function getAllPapers(program: anchor.Program<BioxResearch>) {
       };
     }
     
-    const paperCount = parseInt(programStateResult.data.paperCount);
+    const paperIds = (await program.account.researchPaper.all()).map(({ account }) => account.id.toNumber());
     const papers = [];
     
-    for (let i = 0; i < paperCount; i++) {
-      const paperResult = await getPaperDetails(program, i);
+    for (const paperId of paperIds.sort((a, b) => a - b)) {
+      const paperResult = await getPaperDetails(program, paperId);
       if (paperResult.success) {
         papers.push(paperResult.data);
       }
@@ -530,14 +575,13 @@ export async function fundPaper(
   funderTokenAccount: PublicKey
 ) {
   try {
-    const paperIdBN = new anchor.BN(paperId);
     const amountBN = new anchor.BN(amount);
     
     // Get required PDAs
-    const [paperPda] = getPaperPda(paperIdBN);
-    const [paperTokenAccountPda] = getPaperTokenAccountPda(paperIdBN);
+    const { paperPda, childSeed } = await findPaper(program, new anchor.BN(paperId));
+    const [paperTokenAccountPda] = getPaperTokenAccountPda(childSeed);
     const [platformVaultPda] = getPlatformVaultPda();
-    const [fundingPda] = getFundingPda(paperIdBN, getWalletPublicKey(wallet));
+    const [fundingPda] = getFundingPda(childSeed, getWalletPublicKey(wallet));
     const [programStatePda] = getProgramStatePda();
 
     console.log("Funding paper with PDAs:", {
@@ -547,10 +591,13 @@ export async function fundPaper(
       fundingPda: fundingPda.toString(),
       funderTokenAccount: funderTokenAccount.toString()
     });    const txBuilder = program.methods
-      .fundPaper(paperIdBN, amountBN)
+      .fundPaper(amountBN)
       .accounts({
         funder: getWalletPublicKey(wallet),
+        paper: paperPda,
         funderTokenAccount: funderTokenAccount,
+        paperTokenAccount: paperTokenAccountPda,
+        funding: fundingPda,
       });
 
     const signature = await txBuilder.rpc();
@@ -658,8 +705,8 @@ export async function hasUserVoted(
   voter: PublicKey
 ): Promise<boolean> {
   try {
-    const paperIdBN = new anchor.BN(paperId);
-    const [votePda] = getVotePda(paperIdBN, voter);
+    const { childSeed } = await findPaper(program, new anchor.BN(paperId));
+    const [votePda] = getVotePda(childSeed, voter);
     
     console.log("Checking if user has voted:", {
       paperId,
@@ -811,8 +858,8 @@ export async function getVoteDetails(
   voter: PublicKey
 ) {
   try {
-    const paperIdBN = new anchor.BN(paperId);
-    const [votePda] = getVotePda(paperIdBN, voter);
+    const { childSeed } = await findPaper(program, new anchor.BN(paperId));
+    const [votePda] = getVotePda(childSeed, voter);
     
     console.log("Fetching vote details:", {
       paperId,
@@ -886,10 +933,9 @@ export async function publishPaper(
   paperId: number
 ) {
   try {
-    const paperIdBN = new anchor.BN(paperId);
-    
     // Get required PDAs
-    const [paperPda] = getPaperPda(paperIdBN);
+    const { paperPda, paper } = await findPaper(program, new anchor.BN(paperId));
+    const { categoryIndexPda, categoryPagePda } = await getCategoryPdas(program, paper.category);
     const [programStatePda] = getProgramStatePda();
 
     console.log("Publishing paper with PDAs:", {
@@ -899,9 +945,12 @@ export async function publishPaper(
     });
 
     const txBuilder = program.methods
-      .publishPaper(paperIdBN)
+      .publishPaper()
       .accounts({
         authority: getWalletPublicKey(wallet),
+        paper: paperPda,
+        categoryIndex: categoryIndexPda,
+        categoryPage: categoryPagePda,
       });
 
     const signature = await txBuilder.rpc();
@@ -1081,10 +1130,9 @@ export async function fundPaperWithSOL(
 ) {
   try {
     const walletPublicKey = getWalletPublicKey(wallet);
-    const paperIdBN = new anchor.BN(paperId);
     
     // Get paper PDA to check if it exists and is published
-    const [paperPda] = getPaperPda(paperIdBN);
+    const { paperPda } = await findPaper(program, new anchor.BN(paperId));
       try {
       console.log("Attempting to fetch paper:", {
         paperId: paperId,
@@ -1930,11 +1978,10 @@ export async function fundPaperWithTokenAccountCreation(
     }
     
     // 3. Get all required PDAs as per the contract
-    const paperIdBN = new anchor.BN(paperId);
-    const [paperPda] = getPaperPda(paperIdBN);
-    const [paperTokenAccountPda] = getPaperTokenAccountPda(paperIdBN);
+    const { paperPda, paper, childSeed } = await findPaper(program, new anchor.BN(paperId));
+    const [paperTokenAccountPda] = getPaperTokenAccountPda(childSeed);
     const [platformTokenAccountPda] = getPlatformVaultPda();
-    const [fundingPda] = getFundingPda(paperIdBN, funderPublicKey);
+    const [fundingPda] = getFundingPda(childSeed, funderPublicKey);
     const [programStatePda] = getProgramStatePda();
     
     console.log("PDAs:", {
@@ -1945,9 +1992,8 @@ export async function fundPaperWithTokenAccountCreation(
       funderTokenAccount: funderTokenAccount.toString()
     });
     
-    // 4. Check if paper exists and is published
-    const paper = await program.account.researchPaper.fetch(paperPda);
-    if (!paper.isPublished) {
+    // 4. Check the paper is published
+    if (!paper.status.published) {
       return {
         success: false,
         error: "Paper must be published before it can be funded"
@@ -1957,10 +2003,13 @@ export async function fundPaperWithTokenAccountCreation(
     // 5. Execute funding transaction with exact account structure from IDL
     console.log("Executing funding transaction...");
     const tx = await program.methods
-      .fundPaper(paperIdBN, new anchor.BN(requiredAmount))
+      .fundPaper(new anchor.BN(requiredAmount))
       .accounts({
         funder: funderPublicKey,
+        paper: paperPda,
         funderTokenAccount: funderTokenAccount.address,
+        paperTokenAccount: paperTokenAccountPda,
+        funding: fundingPda,
       })
       .rpc();
     
@@ -0,0 +1,291 @@
+//! Keeper for the BIOX research program.
+//!
+//! Scans for published papers whose funding deadline has passed and submits the permissionless
+//! `expire_paper` crank for each, which opens refunds to their funders. Papers whose voting
+//! window has closed get `finalize_votes`, which snapshots their tally.
+//!
+//! Usage: `biox-keeper [--url <rpc>] [--keypair <path>] [--interval <seconds>] [--once]`
+
+use std::{collections::HashSet, env, error::Error, str::FromStr, thread, time::Duration};
+
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{hash::Hash, instruction::Instruction, system_program, sysvar},
+    AccountDeserialize, Discriminator, InstructionData, ToAccountMetas,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use biox_research::{PaperStatus, ResearchPaper, VoteTally};
+use serde_json::{json, Value};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+type KeeperResult<T> = Result<T, Box<dyn Error>>;
+
+struct Config {
+    url: String,
+    keypair: String,
+    interval: Duration,
+    once: bool,
+}
+
+impl Config {
+    fn from_args() -> KeeperResult<Self> {
+        let home = env::var("HOME").unwrap_or_default();
+        let mut config = Config {
+            url: "http://127.0.0.1:8899".to_string(),
+            keypair: format!("{home}/.config/solana/id.json"),
+            interval: Duration::from_secs(60),
+            once: false,
+        };
+
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--url" => config.url = args.next().ok_or("--url needs a value")?,
+                "--keypair" => config.keypair = args.next().ok_or("--keypair needs a value")?,
+                "--interval" => {
+                    let seconds = args.next().ok_or("--interval needs a value")?.parse()?;
+                    config.interval = Duration::from_secs(seconds);
+                }
+                "--once" => config.once = true,
+                _ => return Err(format!("unknown argument: {arg}").into()),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Minimal JSON-RPC client; the keeper needs only a handful of methods
+struct Rpc {
+    url: String,
+}
+
+impl Rpc {
+    fn call(&self, method: &str, params: Value) -> KeeperResult<Value> {
+        let response: Value = ureq::post(&self.url)
+            .send_json(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))?
+            .into_json()?;
+        if let Some(error) = response.get("error") {
+            return Err(format!("{method} failed: {error}").into());
+        }
+        Ok(response["result"].clone())
+    }
+
+    /// Cluster time from the clock sysvar, so expiry matches what the program will see
+    fn unix_timestamp(&self) -> KeeperResult<i64> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([sysvar::clock::ID.to_string(), { "encoding": "base64" }]),
+        )?;
+        let data = decode_account_data(&result["value"])?;
+        // Clock is slot, epoch_start_timestamp, epoch, leader_schedule_epoch, unix_timestamp
+        let bytes = data.get(32..40).ok_or("clock sysvar too short")?;
+        Ok(i64::from_le_bytes(bytes.try_into()?))
+    }
+
+    /// Every program account of type `T`, which must be `8 + space` bytes long
+    fn accounts<T: AccountDeserialize + Discriminator>(
+        &self,
+        space: usize,
+    ) -> KeeperResult<Vec<(Pubkey, T)>> {
+        let filters = json!([
+            { "dataSize": 8 + space },
+            { "memcmp": {
+                "offset": 0,
+                "bytes": STANDARD.encode(T::DISCRIMINATOR),
+                "encoding": "base64"
+            } }
+        ]);
+        let result = self.call(
+            "getProgramAccounts",
+            json!([biox_research::ID.to_string(), { "encoding": "base64", "filters": filters }]),
+        )?;
+
+        let mut accounts = Vec::new();
+        for entry in result.as_array().ok_or("unexpected getProgramAccounts result")? {
+            let address = Pubkey::from_str(entry["pubkey"].as_str().ok_or("missing pubkey")?)?;
+            let data = decode_account_data(&entry["account"])?;
+            match T::try_deserialize(&mut data.as_slice()) {
+                Ok(account) => accounts.push((address, account)),
+                Err(error) => eprintln!("skipping {address}: {error}"),
+            }
+        }
+        Ok(accounts)
+    }
+
+    fn send(&self, instruction: Instruction, payer: &Keypair) -> KeeperResult<String> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = Hash::from_str(
+            result["value"]["blockhash"]
+                .as_str()
+                .ok_or("missing blockhash")?,
+        )?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        );
+        let encoded = STANDARD.encode(bincode::serialize(&transaction)?);
+        let signature = self.call("sendTransaction", json!([encoded, { "encoding": "base64" }]))?;
+        Ok(signature.as_str().unwrap_or_default().to_string())
+    }
+}
+
+fn decode_account_data(account: &Value) -> KeeperResult<Vec<u8>> {
+    let encoded = account["data"][0].as_str().ok_or("missing account data")?;
+    Ok(STANDARD.decode(encoded)?)
+}
+
+fn expire_instruction(paper: Pubkey) -> Instruction {
+    Instruction {
+        program_id: biox_research::ID,
+        accounts: biox_research::accounts::ExpirePaper { paper }.to_account_metas(None),
+        data: biox_research::instruction::ExpirePaper {}.data(),
+    }
+}
+
+fn finalize_instruction(payer: Pubkey, paper: Pubkey) -> Instruction {
+    let (vote_tally, _) =
+        Pubkey::find_program_address(&[b"vote-tally", paper.as_ref()], &biox_research::ID);
+    Instruction {
+        program_id: biox_research::ID,
+        accounts: biox_research::accounts::FinalizeVotes {
+            payer,
+            paper,
+            vote_tally,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: biox_research::instruction::FinalizeVotes {}.data(),
+    }
+}
+
+/// Whether `expire_paper` would succeed for `paper` at cluster time `now`
+fn is_expirable(paper: &ResearchPaper, now: i64) -> bool {
+    paper.status == PaperStatus::Published && now > paper.funding_deadline
+}
+
+/// Whether `paper`'s voting window has closed by cluster time `now`, so `finalize_votes`
+/// would succeed unless its tally was already snapshotted
+fn is_voting_closed(paper: &ResearchPaper, now: i64) -> bool {
+    paper.voting_closes_at != 0 && now >= paper.voting_closes_at
+}
+
+/// Submit `expire_paper` for every expirable paper and `finalize_votes` for every paper whose
+/// voting window closed without a snapshot, returning how many of each were cranked
+fn crank(rpc: &Rpc, payer: &Keypair) -> KeeperResult<(usize, usize)> {
+    let now = rpc.unix_timestamp()?;
+    let tallied: HashSet<Pubkey> = rpc
+        .accounts::<VoteTally>(VoteTally::SPACE)?
+        .into_iter()
+        .map(|(_, tally)| tally.paper)
+        .collect();
+    let (mut expired, mut finalized) = (0, 0);
+
+    for (address, paper) in rpc.accounts::<ResearchPaper>(ResearchPaper::SPACE)? {
+        if is_expirable(&paper, now) {
+            match rpc.send(expire_instruction(address), payer) {
+                Ok(signature) => {
+                    println!("expired paper {address} ({signature})");
+                    expired += 1;
+                }
+                Err(error) => eprintln!("failed to expire paper {address}: {error}"),
+            }
+        }
+
+        if is_voting_closed(&paper, now) && !tallied.contains(&address) {
+            match rpc.send(finalize_instruction(payer.pubkey(), address), payer) {
+                Ok(signature) => {
+                    println!("finalized votes for paper {address} ({signature})");
+                    finalized += 1;
+                }
+                Err(error) => eprintln!("failed to finalize votes for paper {address}: {error}"),
+            }
+        }
+    }
+
+    Ok((expired, finalized))
+}
+
+fn main() -> KeeperResult<()> {
+    let config = Config::from_args()?;
+    let payer = read_keypair_file(&config.keypair)
+        .map_err(|error| format!("reading keypair {}: {error}", config.keypair))?;
+    let rpc = Rpc { url: config.url };
+
+    loop {
+        match crank(&rpc, &payer) {
+            Ok((expired, finalized)) => println!(
+                "scan complete, {expired} paper(s) expired, {finalized} tally(ies) finalized"
+            ),
+            Err(error) if config.once => return Err(error),
+            Err(error) => eprintln!("scan failed: {error}"),
+        }
+        if config.once {
+            return Ok(());
+        }
+        thread::sleep(config.interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biox_research::{cid::ContentId, ResearchCategory};
+
+    fn paper(status: PaperStatus, funding_deadline: i64, voting_closes_at: i64) -> ResearchPaper {
+        ResearchPaper {
+            id: 0,
+            author: Pubkey::new_unique(),
+            created_at: 0,
+            updated_at: 0,
+            funding_goal: 1_000_000,
+            funding_current: 0,
+            funding_deadline,
+            upvotes: 0,
+            downvotes: 0,
+            status,
+            review_score: 0,
+            review_count: 0,
+            bump: 0,
+            version: ResearchPaper::CURRENT_VERSION,
+            manuscript_version: 1,
+            citations_out: 0,
+            citations_in: 0,
+            category: ResearchCategory::Other,
+            nonce: 0,
+            content_id: ContentId::parse("QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n").unwrap(),
+            voting_opens_at: 0,
+            voting_closes_at,
+            legacy_seeds: false,
+            earliest_unlock_at: 0,
+            reserved: [0; ResearchPaper::RESERVED_BYTES],
+        }
+    }
+
+    #[test]
+    fn expires_published_papers_past_their_deadline() {
+        let published = paper(PaperStatus::Published, 100, 0);
+        assert!(!is_expirable(&published, 100));
+        assert!(is_expirable(&published, 101));
+    }
+
+    #[test]
+    fn skips_papers_that_are_not_published() {
+        for status in [PaperStatus::Draft, PaperStatus::FullyFunded, PaperStatus::Expired] {
+            assert!(!is_expirable(&paper(status, 100, 0), 101));
+        }
+    }
+
+    #[test]
+    fn finalizes_once_the_voting_window_closes() {
+        let published = paper(PaperStatus::Published, 100, 200);
+        assert!(!is_voting_closed(&published, 199));
+        assert!(is_voting_closed(&published, 200));
+        assert!(!is_voting_closed(&paper(PaperStatus::Draft, 100, 0), 200));
+    }
+}
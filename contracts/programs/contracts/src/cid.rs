@@ -0,0 +1,286 @@
+//! Content identifier (CID) parsing for IPFS references.
+//!
+//! Instructions accept CIDs in their usual string form and store them as a fixed-size
+//! `ContentId`. Only multihashes with 32-byte digests are accepted, which covers the hash
+//! functions IPFS produces by default.
+
+use anchor_lang::prelude::*;
+
+use crate::ResearchError;
+
+/// Multicodec for dag-pb, the codec implied by every CIDv0
+pub const DAG_PB: u32 = 0x70;
+/// Multihash code for sha2-256, the hash implied by every CIDv0
+pub const SHA2_256: u32 = 0x12;
+
+pub const DIGEST_LENGTH: usize = 32;
+pub const MAX_CID_LENGTH: usize = 100;
+const CID_V0_LENGTH: usize = 46;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE16_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+/// Multihash codes with 32-byte digests
+const SUPPORTED_HASHES: [u32; 5] = [
+    SHA2_256,
+    0x16,   // sha3-256
+    0x1b,   // keccak-256
+    0x1e,   // blake3
+    0xb220, // blake2b-256
+];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ContentId {
+    pub version: u8,
+    pub codec: u32,
+    pub hash_code: u32,
+    pub digest: [u8; DIGEST_LENGTH],
+}
+
+impl ContentId {
+    pub const SPACE: usize = 1 + 4 + 4 + DIGEST_LENGTH;
+    /// Held by papers migrated with a legacy hash that is not a CID, until the author sets one
+    pub const UNSET: Self = Self {
+        version: 0,
+        codec: 0,
+        hash_code: 0,
+        digest: [0; DIGEST_LENGTH],
+    };
+
+    /// Parse a CIDv0 (`Qm...`) or a CIDv1 in base32 (`b...`), base58btc (`z...`) or
+    /// base16 (`f...`) multibase encoding
+    pub fn parse(cid: &str) -> Result<Self> {
+        require!(!cid.is_empty() && cid.len() <= MAX_CID_LENGTH, ResearchError::InvalidContentId);
+
+        if cid.len() == CID_V0_LENGTH && cid.starts_with("Qm") {
+            let bytes = decode_base58(cid.as_bytes())?;
+            let (hash_code, digest) = parse_multihash(&bytes)?;
+            require!(hash_code == SHA2_256, ResearchError::InvalidContentId);
+            return Ok(Self {
+                version: 0,
+                codec: DAG_PB,
+                hash_code,
+                digest,
+            });
+        }
+
+        let (prefix, encoded) = cid.as_bytes().split_first().unwrap();
+        let bytes = match prefix {
+            b'b' => decode_base32(encoded)?,
+            b'z' => decode_base58(encoded)?,
+            b'f' => decode_base16(encoded)?,
+            _ => return err!(ResearchError::InvalidContentId),
+        };
+
+        let mut cursor = bytes.as_slice();
+        require!(read_varint(&mut cursor)? == 1, ResearchError::InvalidContentId);
+        let codec = u32::try_from(read_varint(&mut cursor)?)
+            .map_err(|_| error!(ResearchError::InvalidContentId))?;
+        let (hash_code, digest) = parse_multihash(cursor)?;
+
+        Ok(Self {
+            version: 1,
+            codec,
+            hash_code,
+            digest,
+        })
+    }
+
+    /// Parse an optional CID where the empty string means none
+    pub fn parse_optional(cid: &str) -> Result<Option<Self>> {
+        if cid.is_empty() {
+            return Ok(None);
+        }
+        Self::parse(cid).map(Some)
+    }
+
+    pub fn is_unset(&self) -> bool {
+        *self == Self::UNSET
+    }
+}
+
+/// Parse a complete multihash, returning its hash code and digest
+fn parse_multihash(mut bytes: &[u8]) -> Result<(u32, [u8; DIGEST_LENGTH])> {
+    let hash_code = read_varint(&mut bytes)?;
+    let length = read_varint(&mut bytes)?;
+    require!(
+        length == DIGEST_LENGTH as u64 && bytes.len() == DIGEST_LENGTH,
+        ResearchError::InvalidContentId
+    );
+
+    let hash_code = u32::try_from(hash_code).map_err(|_| error!(ResearchError::InvalidContentId))?;
+    require!(SUPPORTED_HASHES.contains(&hash_code), ResearchError::InvalidContentId);
+
+    Ok((hash_code, bytes.try_into().unwrap()))
+}
+
+/// Read an unsigned LEB128 varint, which multiformats caps at 9 bytes and requires to be minimal
+fn read_varint(bytes: &mut &[u8]) -> Result<u64> {
+    let mut value: u64 = 0;
+    for i in 0..9 {
+        let (&byte, rest) = bytes
+            .split_first()
+            .ok_or_else(|| error!(ResearchError::InvalidContentId))?;
+        *bytes = rest;
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            require!(byte != 0 || i == 0, ResearchError::InvalidContentId);
+            return Ok(value);
+        }
+    }
+    err!(ResearchError::InvalidContentId)
+}
+
+fn alphabet_index(alphabet: &[u8], symbol: u8) -> Result<u32> {
+    alphabet
+        .iter()
+        .position(|&candidate| candidate == symbol)
+        .map(|index| index as u32)
+        .ok_or_else(|| error!(ResearchError::InvalidContentId))
+}
+
+fn decode_base58(input: &[u8]) -> Result<Vec<u8>> {
+    // Accumulate little-endian, then restore the leading zero bytes encoded as '1's
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for &symbol in input {
+        let mut carry = alphabet_index(BASE58_ALPHABET, symbol)?;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let leading_zeros = input.iter().take_while(|&&symbol| symbol == b'1').count();
+    bytes.resize(bytes.len() + leading_zeros, 0);
+    bytes.reverse();
+    Ok(bytes)
+}
+
+fn decode_base32(input: &[u8]) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &symbol in input {
+        buffer = (buffer << 5) | alphabet_index(BASE32_ALPHABET, symbol)?;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    // Unpadded base32 leaves fewer than 5 trailing bits, all zero
+    require!(bits < 5 && buffer == 0, ResearchError::InvalidContentId);
+    Ok(bytes)
+}
+
+// `usize::is_multiple_of` is newer than the rustc in Solana's platform tools
+#[allow(clippy::manual_is_multiple_of)]
+fn decode_base16(input: &[u8]) -> Result<Vec<u8>> {
+    require!(input.len() % 2 == 0, ResearchError::InvalidContentId);
+    input
+        .chunks(2)
+        .map(|pair| {
+            let high = alphabet_index(BASE16_ALPHABET, pair[0])?;
+            let low = alphabet_index(BASE16_ALPHABET, pair[1])?;
+            Ok((high << 4 | low) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // sha2-256 of the empty string, in every encoding below
+    const DIGEST: [u8; DIGEST_LENGTH] = [
+        0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f, 0xb9,
+        0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b, 0x78, 0x52,
+        0xb8, 0x55,
+    ];
+    const CID_V0: &str = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n";
+    const CID_V1_BASE32: &str = "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+    const CID_V1_BASE58: &str = "zdj7Wkkhxcu2rsiN6GUyHCLsSLL47kdUNfjbFqBUUhMFTZKBi";
+    const CID_V1_BASE16: &str =
+        "f01701220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    fn dag_pb(version: u8) -> ContentId {
+        ContentId {
+            version,
+            codec: DAG_PB,
+            hash_code: SHA2_256,
+            digest: DIGEST,
+        }
+    }
+
+    #[test]
+    fn parses_cid_v0() {
+        assert_eq!(ContentId::parse(CID_V0).unwrap(), dag_pb(0));
+    }
+
+    #[test]
+    fn parses_cid_v1_in_each_multibase() {
+        for cid in [CID_V1_BASE32, CID_V1_BASE58, CID_V1_BASE16] {
+            assert_eq!(ContentId::parse(cid).unwrap(), dag_pb(1), "{cid}");
+        }
+    }
+
+    #[test]
+    fn parses_cid_v1_with_other_codecs_and_hashes() {
+        // raw codec, blake3
+        let cid = ContentId::parse("bafkr4ihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku").unwrap();
+        assert_eq!(cid.codec, 0x55);
+        assert_eq!(cid.hash_code, 0x1e);
+        assert_eq!(cid.digest, DIGEST);
+    }
+
+    #[test]
+    fn empty_optional_cid_is_none() {
+        assert_eq!(ContentId::parse_optional("").unwrap(), None);
+        assert_eq!(ContentId::parse_optional(CID_V0).unwrap(), Some(dag_pb(0)));
+    }
+
+    #[test]
+    fn rejects_malformed_cids() {
+        let too_long = format!("f{}", "0".repeat(MAX_CID_LENGTH));
+        let malformed = [
+            "",
+            too_long.as_str(),
+            // Not base58: '0', 'O', 'I' and 'l' are excluded from the alphabet
+            "QmXoYLX8lM5nOQmXoYLX8lM5nOQmXoYLX8lM5nOQmXoYLX",
+            // The legacy test fixture, which is not a CID at all
+            "QmXoYLX8...lM5nO",
+            // CIDv0 with a truncated digest
+            "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1",
+            // Unknown multibase prefix
+            "mAXASIOOwxEKY/BwUmvv0yJlvuSQnrkHkZJuTTKSVmRt4UrhV",
+            // Uppercase base32 and base16
+            "BAFYBEIHDWDCEFGH4DQKJV67UZCMW7OJEE6XEDZDETOJUZJEVTENXQUVYKU",
+            "F01701220E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855",
+            // Odd-length base16
+            "f01701220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b85",
+            // Non-zero trailing bits in base32
+            "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvykv",
+            // CIDv2
+            "f02701220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            // Unsupported hash (sha2-512 code with a 32-byte digest)
+            "f01701320e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            // Digest length that doesn't match the bytes that follow
+            "f01701221e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            // Trailing byte after the digest
+            "f01701220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b85500",
+            // Non-minimal varint for the version
+            "f8100701220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ];
+        for cid in malformed {
+            assert!(ContentId::parse(cid).is_err(), "{cid}");
+        }
+    }
+}
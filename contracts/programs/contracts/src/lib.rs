@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub mod cid;
+
+use cid::ContentId;
 
 declare_id!("4TsLtFAfkbpcFjesanK4ojZNTK1bsQPfPuVxt5g19hhM");
 
@@ -19,12 +25,31 @@ pub mod biox_research {
         program_state.min_funding_goal = 1_000_000; // 1 token minimum
         program_state.max_funding_period = 90 * 24 * 60 * 60; // 90 days in seconds
         program_state.bump = ctx.bumps.program_state;
-        program_state.is_paused = false;
+        program_state.pause_flags = 0;
+        program_state.pending_admin = None;
+        program_state.settings_delay = ProgramState::DEFAULT_SETTINGS_DELAY;
+        program_state.vote_weight_divisor = ProgramState::DEFAULT_VOTE_WEIGHT_DIVISOR;
+        program_state.vote_weight_cap = ProgramState::DEFAULT_VOTE_WEIGHT_CAP;
+        program_state.governance_mint = Pubkey::default();
+        program_state.governance_quorum = 0;
+        program_state.governance_threshold_bps = ProgramState::DEFAULT_GOVERNANCE_THRESHOLD_BPS;
+        program_state.governance_voting_period = ProgramState::DEFAULT_GOVERNANCE_VOTING_PERIOD;
+        program_state.proposal_count = 0;
+        program_state.version = ProgramState::CURRENT_VERSION;
+        program_state.voting_mode = VotingMode::Linear;
+        program_state.vote_credits_per_epoch = ProgramState::DEFAULT_VOTE_CREDITS_PER_EPOCH;
+        program_state.vote_epoch_duration = ProgramState::DEFAULT_VOTE_EPOCH_DURATION;
+        program_state.governance_decimals = 0;
+        program_state.vote_weight_zero_balance = ProgramState::DEFAULT_VOTE_WEIGHT_ZERO_BALANCE;
+        program_state.vote_weight_curve = WeightCurve::Linear;
+        program_state.voting_window_duration = ProgramState::DEFAULT_VOTING_WINDOW_DURATION;
+        program_state.reserved = [0; ProgramState::RESERVED_BYTES];
 
         Ok(())
     }
 
     /// Submit a new research paper
+    #[allow(clippy::too_many_arguments)]
     pub fn submit_paper(
         ctx: Context<SubmitPaper>,
         title: String,
@@ -33,37 +58,43 @@ pub mod biox_research {
         authors: Vec<String>,
         funding_goal: u64,
         funding_period_days: u64,
+        category: ResearchCategory,
+        keywords: Vec<String>,
     ) -> Result<()> {
         let program_state = &ctx.accounts.program_state;
         
         // Validate program state
-        require!(!program_state.is_paused, ResearchError::ProgramPaused);
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
         
         // Validate inputs
-        require!(title.len() > 0 && title.len() <= 100, ResearchError::InvalidTitle);
-        require!(abstract_text.len() > 0 && abstract_text.len() <= 1000, ResearchError::InvalidAbstract);
-        require!(ipfs_hash.len() > 0 && ipfs_hash.len() <= 100, ResearchError::InvalidIPFSHash);
-        require!(authors.len() > 0 && authors.len() <= 10, ResearchError::InvalidAuthors);
+        PaperMetadata::validate_title(&title)?;
+        PaperMetadata::validate_abstract(&abstract_text)?;
+        let content_id = ContentId::parse(&ipfs_hash)?;
+        PaperMetadata::validate_authors(&authors)?;
+        PaperMetadata::validate_keywords(&keywords)?;
         require!(funding_goal >= program_state.min_funding_goal, ResearchError::FundingGoalTooLow);
-        require!(funding_period_days > 0 && funding_period_days <= 365, ResearchError::InvalidFundingPeriod);
+        require!(
+            funding_period_days > 0
+                && funding_period_days <= (program_state.max_funding_period / (24 * 60 * 60)) as u64,
+            ResearchError::InvalidFundingPeriod
+        );
 
         let current_time = Clock::get()?.unix_timestamp;
         let funding_deadline = current_time + (funding_period_days * 24 * 60 * 60) as i64;
-        
-        let program_state = &mut ctx.accounts.program_state;
-        let paper_id = program_state.paper_count;
-        program_state.paper_count += 1;
 
+        let author = ctx.accounts.author.key();
+        let author_index = &mut ctx.accounts.author_index;
+        let nonce = author_index.paper_count;
+        author_index.author = author;
+        author_index.paper_count = nonce.checked_add(1).unwrap();
+        author_index.bump = ctx.bumps.author_index;
+
+        let paper_id = ResearchPaper::derive_id(&author, nonce);
         let paper = &mut ctx.accounts.paper;
         paper.id = paper_id;
-        paper.author = ctx.accounts.author.key();
-        paper.title = title.clone();
-        paper.abstract_text = abstract_text;
-        paper.ipfs_hash = ipfs_hash;
-        paper.authors = authors;
+        paper.author = author;
         paper.created_at = current_time;
         paper.updated_at = current_time;
-        paper.is_published = false;
         paper.funding_goal = funding_goal;
         paper.funding_current = 0;
         paper.funding_deadline = funding_deadline;
@@ -73,10 +104,39 @@ pub mod biox_research {
         paper.review_score = 0;
         paper.review_count = 0;
         paper.bump = ctx.bumps.paper;
+        paper.version = ResearchPaper::CURRENT_VERSION;
+        paper.manuscript_version = 1;
+        paper.citations_out = 0;
+        paper.citations_in = 0;
+        paper.category = category;
+        paper.nonce = nonce;
+        paper.content_id = content_id;
+        paper.reserved = [0; ResearchPaper::RESERVED_BYTES];
+
+        let metadata = &mut ctx.accounts.metadata;
+        metadata.paper = ctx.accounts.paper.key();
+        metadata.bump = ctx.bumps.metadata;
+        metadata.title = title.clone();
+        metadata.abstract_text = abstract_text;
+        metadata.authors = authors;
+        metadata.keywords = keywords;
+
+        ctx.accounts.manuscript_registry.register(
+            content_id,
+            ctx.accounts.paper.key(),
+            author,
+            current_time,
+            ctx.bumps.manuscript_registry,
+        )?;
+
+        let profile = &mut ctx.accounts.author_profile;
+        profile.initialize_if_new(author, current_time, ctx.bumps.author_profile);
+        profile.papers_submitted = profile.papers_submitted.checked_add(1).unwrap();
+        profile.updated_at = current_time;
 
         emit!(PaperSubmittedEvent {
             paper_id,
-            author: ctx.accounts.author.key(),
+            author,
             title,
             timestamp: current_time,
         });
@@ -84,40 +144,415 @@ pub mod biox_research {
         Ok(())
     }
 
+    /// Edit a draft paper (author only), resizing its metadata account to fit
+    pub fn update_paper(
+        ctx: Context<UpdatePaper>,
+        title: Option<String>,
+        abstract_text: Option<String>,
+        ipfs_hash: Option<String>,
+        authors: Option<Vec<String>>,
+        keywords: Option<Vec<String>>,
+    ) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
+        require!(
+            title.is_some()
+                || abstract_text.is_some()
+                || ipfs_hash.is_some()
+                || authors.is_some()
+                || keywords.is_some(),
+            ResearchError::EmptyPaperUpdate
+        );
+
+        let paper = &mut ctx.accounts.paper;
+        require!(paper.author == ctx.accounts.author.key(), ResearchError::Unauthorized);
+        // A paper migrated without a valid CID may set its manuscript whatever its status
+        let sets_missing_manuscript = paper.content_id.is_unset()
+            && ipfs_hash.is_some()
+            && title.is_none()
+            && abstract_text.is_none()
+            && authors.is_none()
+            && keywords.is_none();
+        require!(
+            paper.status == PaperStatus::Draft || sets_missing_manuscript,
+            ResearchError::InvalidPaperStatus
+        );
+
+        let metadata = &mut ctx.accounts.metadata;
+        if let Some(title) = title {
+            PaperMetadata::validate_title(&title)?;
+            metadata.title = title;
+        }
+
+        if let Some(abstract_text) = abstract_text {
+            PaperMetadata::validate_abstract(&abstract_text)?;
+            metadata.abstract_text = abstract_text;
+        }
+
+        // Re-submitting the current manuscript leaves its registration as it is
+        if let Some(ipfs_hash) = ipfs_hash {
+            let content_id = ContentId::parse(&ipfs_hash)?;
+            if content_id != paper.content_id {
+                let previous_registry = ctx
+                    .accounts
+                    .previous_manuscript_registry
+                    .as_ref()
+                    .ok_or(ResearchError::ManuscriptRegistryRequired)?;
+                release_manuscript(
+                    previous_registry,
+                    &paper.key(),
+                    &ctx.accounts.author.to_account_info(),
+                )?;
+
+                let registry = ctx
+                    .accounts
+                    .manuscript_registry
+                    .as_mut()
+                    .ok_or(ResearchError::ManuscriptRegistryRequired)?;
+                registry.register(
+                    content_id,
+                    paper.key(),
+                    paper.author,
+                    Clock::get()?.unix_timestamp,
+                    ctx.bumps.manuscript_registry.unwrap(),
+                )?;
+                paper.content_id = content_id;
+            }
+        }
+
+        if let Some(authors) = authors {
+            PaperMetadata::validate_authors(&authors)?;
+            metadata.authors = authors;
+        }
+
+        if let Some(keywords) = keywords {
+            PaperMetadata::validate_keywords(&keywords)?;
+            metadata.keywords = keywords;
+        }
+
+        paper.updated_at = Clock::get()?.unix_timestamp;
+        let new_len = 8 + metadata.space();
+
+        emit!(PaperUpdatedEvent {
+            paper_id: paper.id,
+            author: paper.author,
+            title: metadata.title.clone(),
+            timestamp: paper.updated_at,
+        });
+
+        resize_account(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.author.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_len,
+        )?;
+
+        Ok(())
+    }
+
     /// Publish a paper (by author) or approve (by admin)
-    pub fn publish_paper(ctx: Context<PublishPaper>, _paper_id: u64) -> Result<()> {
+    pub fn publish_paper(ctx: Context<PublishPaper>) -> Result<()> {
         let paper = &mut ctx.accounts.paper;
         let program_state = &ctx.accounts.program_state;
         let authority = ctx.accounts.authority.key();
 
-        require!(!program_state.is_paused, ResearchError::ProgramPaused);
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
         require!(
             paper.author == authority || program_state.admin == authority,
             ResearchError::Unauthorized
         );
-        require!(paper.status == PaperStatus::Draft, ResearchError::InvalidPaperStatus);
+        // A paper under review can only be approved by the admin
+        require!(
+            paper.status != PaperStatus::UnderReview || program_state.admin == authority,
+            ResearchError::Unauthorized
+        );
 
-        paper.is_published = true;
-        paper.status = PaperStatus::Published;
-        paper.updated_at = Clock::get()?.unix_timestamp;
+        require!(!paper.content_id.is_unset(), ResearchError::ManuscriptRequired);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        paper.transition(PaperStatus::Published, current_time)?;
+        paper.open_voting(program_state, current_time);
+
+        // Append to the category index so clients can enumerate published papers by subject
+        let category_index = &mut ctx.accounts.category_index;
+        let category_page = &mut ctx.accounts.category_page;
+        if category_page.papers.is_empty() {
+            category_page.category = paper.category;
+            category_page.page = category_index.current_page();
+            category_page.bump = ctx.bumps.category_page;
+        }
+        category_page.papers.push(paper.key());
+        category_index.category = paper.category;
+        category_index.paper_count = category_index.paper_count.checked_add(1).unwrap();
+        category_index.bump = ctx.bumps.category_index;
+
+        let profile = &mut ctx.accounts.author_profile;
+        profile.initialize_if_new(paper.author, current_time, ctx.bumps.author_profile);
+        profile.papers_published = profile.papers_published.checked_add(1).unwrap();
+        profile.reputation = profile
+            .reputation
+            .checked_add(ResearcherProfile::REPUTATION_PER_PUBLICATION)
+            .unwrap();
+        profile.updated_at = paper.updated_at;
 
         emit!(PaperPublishedEvent {
             paper_id: paper.id,
             author: paper.author,
+            voting_closes_at: paper.voting_closes_at,
             timestamp: paper.updated_at,
         });
 
         Ok(())
     }
 
+    /// Submit a draft paper for admin review instead of publishing it directly (author only)
+    pub fn request_review(ctx: Context<ReviewPaper>) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_REVIEWS), ResearchError::ProgramPaused);
+
+        let paper = &mut ctx.accounts.paper;
+        require!(paper.author == ctx.accounts.authority.key(), ResearchError::Unauthorized);
+        paper.transition(PaperStatus::UnderReview, Clock::get()?.unix_timestamp)
+    }
+
+    /// Reject a paper under review (admin only)
+    pub fn reject_paper(ctx: Context<ReviewPaper>) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_REVIEWS), ResearchError::ProgramPaused);
+        require!(program_state.admin == ctx.accounts.authority.key(), ResearchError::Unauthorized);
+        ctx.accounts
+            .paper
+            .transition(PaperStatus::Rejected, Clock::get()?.unix_timestamp)
+    }
+
+    /// Withdraw a paper that was never published (author only)
+    pub fn cancel_paper(ctx: Context<ReviewPaper>) -> Result<()> {
+        let paper = &mut ctx.accounts.paper;
+        require!(paper.author == ctx.accounts.authority.key(), ResearchError::Unauthorized);
+        paper.transition(PaperStatus::Cancelled, Clock::get()?.unix_timestamp)
+    }
+
+    /// Override a published paper's voting window (admin only). A window that has already
+    /// closed is final, so its tally snapshot cannot move, and no window may outlast a lock
+    /// that backs one of its votes.
+    pub fn set_voting_window(ctx: Context<SetVotingWindow>, opens_at: i64, closes_at: i64) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+
+        let paper = &mut ctx.accounts.paper;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(paper.status.is_published(), ResearchError::PaperNotPublished);
+        require!(current_time < paper.voting_closes_at, ResearchError::VotingClosed);
+        require!(
+            opens_at < closes_at && current_time < closes_at,
+            ResearchError::InvalidVotingWindow
+        );
+        // Locks behind existing votes were only checked against the old closing time
+        require!(
+            paper.earliest_unlock_at == 0 || closes_at <= paper.earliest_unlock_at,
+            ResearchError::LockTooShort
+        );
+
+        paper.voting_opens_at = opens_at;
+        paper.voting_closes_at = closes_at;
+        paper.updated_at = current_time;
+
+        emit!(VotingWindowChangedEvent {
+            paper_id: paper.id,
+            opens_at,
+            closes_at,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Publish a new manuscript revision of a published paper (author only).
+    /// Version 1 is the CID stored on the paper itself.
+    pub fn publish_revision(
+        ctx: Context<PublishRevision>,
+        ipfs_hash: String,
+        content_hash: [u8; 32],
+        changelog_cid: String,
+    ) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
+
+        let paper = &mut ctx.accounts.paper;
+        require!(paper.author == ctx.accounts.author.key(), ResearchError::Unauthorized);
+        require!(
+            matches!(
+                paper.status,
+                PaperStatus::Published | PaperStatus::FullyFunded | PaperStatus::Completed
+            ),
+            ResearchError::PaperNotPublished
+        );
+        let content_id = ContentId::parse(&ipfs_hash)?;
+        let changelog_cid = ContentId::parse(&changelog_cid)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let version = paper.manuscript_version.checked_add(1).unwrap();
+        paper.manuscript_version = version;
+        paper.updated_at = current_time;
+
+        let revision = &mut ctx.accounts.revision;
+        revision.paper_id = paper.id;
+        revision.version = version;
+        revision.content_id = content_id;
+        revision.content_hash = content_hash;
+        revision.changelog_cid = changelog_cid;
+        revision.author = paper.author;
+        revision.timestamp = current_time;
+        revision.bump = ctx.bumps.revision;
+
+        emit!(PaperRevisedEvent {
+            paper_id: paper.id,
+            version,
+            content_id,
+            content_hash,
+            changelog_cid,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Register a citation from the author's paper to another paper on the platform
+    pub fn add_citation(ctx: Context<AddCitation>) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
+        require!(
+            ctx.accounts.citing_paper.key() != ctx.accounts.cited_paper.key(),
+            ResearchError::InvalidCitation
+        );
+
+        let citing_paper = &mut ctx.accounts.citing_paper;
+        let cited_paper = &mut ctx.accounts.cited_paper;
+        require!(citing_paper.author == ctx.accounts.author.key(), ResearchError::Unauthorized);
+        require!(cited_paper.status.is_published(), ResearchError::PaperNotPublished);
+
+        citing_paper.citations_out = citing_paper.citations_out.checked_add(1).unwrap();
+        cited_paper.citations_in = cited_paper.citations_in.checked_add(1).unwrap();
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let target = CitationTarget::Paper(cited_paper.id);
+
+        let citation = &mut ctx.accounts.citation;
+        citation.citing_paper_id = citing_paper.id;
+        citation.target = target.clone();
+        citation.created_by = ctx.accounts.author.key();
+        citation.timestamp = current_time;
+        citation.bump = ctx.bumps.citation;
+
+        emit!(CitationAddedEvent {
+            citing_paper_id: citing_paper.id,
+            target,
+            citations_out: citing_paper.citations_out,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Register a citation from the author's paper to an external work identified by DOI
+    pub fn add_external_citation(ctx: Context<AddExternalCitation>, doi: String) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
+        Citation::validate_doi(&doi)?;
+
+        let citing_paper = &mut ctx.accounts.citing_paper;
+        require!(citing_paper.author == ctx.accounts.author.key(), ResearchError::Unauthorized);
+
+        citing_paper.citations_out = citing_paper.citations_out.checked_add(1).unwrap();
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let target = CitationTarget::Doi(doi);
+
+        let citation = &mut ctx.accounts.citation;
+        citation.citing_paper_id = citing_paper.id;
+        citation.target = target.clone();
+        citation.created_by = ctx.accounts.author.key();
+        citation.timestamp = current_time;
+        citation.bump = ctx.bumps.citation;
+
+        emit!(CitationAddedEvent {
+            citing_paper_id: citing_paper.id,
+            target,
+            citations_out: citing_paper.citations_out,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Commit to an unpublished manuscript without disclosing it. The commitment is
+    /// sha256(committer || manuscript CID digest || salt).
+    pub fn commit_priority(ctx: Context<CommitPriority>, commitment: [u8; 32]) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_SUBMISSIONS), ResearchError::ProgramPaused);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let priority = &mut ctx.accounts.priority_commitment;
+        priority.committer = ctx.accounts.committer.key();
+        priority.commitment = commitment;
+        priority.committed_at = current_time;
+        priority.paper = None;
+        priority.revealed_at = 0;
+        priority.bump = ctx.bumps.priority_commitment;
+
+        emit!(PriorityCommittedEvent {
+            committer: priority.committer,
+            commitment,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Link a priority commitment to the committer's paper by revealing the salt
+    pub fn reveal_priority(ctx: Context<RevealPriority>, salt: [u8; 32]) -> Result<()> {
+        let paper = &ctx.accounts.paper;
+        let priority = &mut ctx.accounts.priority_commitment;
+        require!(priority.committer == ctx.accounts.committer.key(), ResearchError::Unauthorized);
+        require!(paper.author == priority.committer, ResearchError::Unauthorized);
+        require!(priority.paper.is_none(), ResearchError::CommitmentAlreadyRevealed);
+        require!(!paper.content_id.is_unset(), ResearchError::ManuscriptRequired);
+
+        let preimage = [
+            priority.committer.as_ref(),
+            paper.content_id.digest.as_ref(),
+            salt.as_ref(),
+        ]
+        .concat();
+        require!(
+            hash(&preimage).to_bytes() == priority.commitment,
+            ResearchError::InvalidCommitmentReveal
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        priority.paper = Some(paper.key());
+        priority.revealed_at = current_time;
+
+        emit!(PriorityRevealedEvent {
+            committer: priority.committer,
+            paper: paper.key(),
+            commitment: priority.commitment,
+            committed_at: priority.committed_at,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
     /// Fund a published paper
-    pub fn fund_paper(ctx: Context<FundPaper>, paper_id: u64, amount: u64) -> Result<()> {
+    pub fn fund_paper(ctx: Context<FundPaper>, amount: u64) -> Result<()> {
         let program_state = &ctx.accounts.program_state;
-        require!(!program_state.is_paused, ResearchError::ProgramPaused);
+        require!(!program_state.is_paused(ProgramState::PAUSE_FUNDING), ResearchError::ProgramPaused);
         require!(amount > 0, ResearchError::InvalidAmount);
 
         let paper = &mut ctx.accounts.paper;
-        require!(paper.is_published, ResearchError::PaperNotPublished);
+        require!(paper.status.is_published(), ResearchError::PaperNotPublished);
+        require!(paper.status != PaperStatus::Retracted, ResearchError::PaperRetracted);
         require!(paper.status == PaperStatus::Published, ResearchError::InvalidPaperStatus);
 
         let current_time = Clock::get()?.unix_timestamp;
@@ -160,7 +595,7 @@ pub mod biox_research {
 
         // Create funding record
         let funding = &mut ctx.accounts.funding;
-        funding.paper_id = paper_id;
+        funding.paper_id = paper.id;
         funding.funder = ctx.accounts.funder.key();
         funding.amount = net_amount;
         funding.platform_fee = platform_fee;
@@ -171,9 +606,14 @@ pub mod biox_research {
         let program_state = &mut ctx.accounts.program_state;
         program_state.total_funding = program_state.total_funding.checked_add(net_amount).unwrap();
 
+        let profile = &mut ctx.accounts.author_profile;
+        profile.initialize_if_new(paper.author, current_time, ctx.bumps.author_profile);
+        profile.total_funding_raised = profile.total_funding_raised.checked_add(net_amount).unwrap();
+        profile.updated_at = current_time;
+
         // Check if funding goal reached
         if paper.funding_current >= paper.funding_goal {
-            paper.status = PaperStatus::FullyFunded;
+            paper.transition(PaperStatus::FullyFunded, current_time)?;
         }
 
         emit!(PaperFundedEvent {
@@ -188,48 +628,135 @@ pub mod biox_research {
         Ok(())
     }
 
-    /// Vote on a paper (with weighted voting)
-    pub fn vote_paper(ctx: Context<VotePaper>, paper_id: u64, is_upvote: bool) -> Result<()> {
+    /// Lock BIOX in the governance vault until `now + duration` for decaying voting power.
+    /// Calling again adds to the lock and may extend, but never shorten, its unlock time.
+    pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, duration: i64) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_VOTING), ResearchError::ProgramPaused);
+        require!(
+            duration > 0 && duration <= VoteEscrow::MAX_LOCK_DURATION,
+            ResearchError::InvalidLockDuration
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let unlock_at = current_time + duration;
+        let escrow = &mut ctx.accounts.vote_escrow;
+        require!(unlock_at >= escrow.unlock_at, ResearchError::InvalidLockDuration);
+        require!(amount > 0 || escrow.amount > 0, ResearchError::InvalidAmount);
+
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.owner_token_account.to_account_info(),
+                        to: ctx.accounts.governance_vault.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        escrow.owner = ctx.accounts.owner.key();
+        escrow.amount = escrow.amount.checked_add(amount).unwrap();
+        escrow.locked_at = current_time;
+        escrow.unlock_at = unlock_at;
+        escrow.bump = ctx.bumps.vote_escrow;
+
+        emit!(TokensLockedEvent {
+            owner: escrow.owner,
+            amount,
+            total_locked: escrow.amount,
+            unlock_at,
+            voting_power: escrow.voting_power(current_time),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Return an expired lock's tokens and rent to its owner
+    pub fn withdraw_lock(ctx: Context<WithdrawLock>) -> Result<()> {
+        let escrow = &ctx.accounts.vote_escrow;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= escrow.unlock_at, ResearchError::LockNotExpired);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.governance_vault.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.governance_vault.to_account_info(),
+                },
+                &[&[b"governance-vault", &[ctx.bumps.governance_vault]]],
+            ),
+            escrow.amount,
+        )?;
+
+        emit!(LockWithdrawnEvent {
+            owner: escrow.owner,
+            amount: escrow.amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Vote on a paper. In linear mode the weight comes from the voter's vote-escrowed BIOX;
+    /// in quadratic mode the voter spends `credits` from the allowance that BIOX grants this
+    /// epoch, for a weight of their square root.
+    pub fn vote_paper(ctx: Context<VotePaper>, is_upvote: bool, credits: u64) -> Result<()> {
         let program_state = &ctx.accounts.program_state;
-        require!(!program_state.is_paused, ResearchError::ProgramPaused);
+        require!(!program_state.is_paused(ProgramState::PAUSE_VOTING), ResearchError::ProgramPaused);
 
         let paper = &mut ctx.accounts.paper;
-        require!(paper.is_published, ResearchError::PaperNotPublished);
+        require!(paper.status.is_published(), ResearchError::PaperNotPublished);
+        require!(paper.status != PaperStatus::Retracted, ResearchError::PaperRetracted);
+        require!(!paper.status.is_final(), ResearchError::InvalidPaperStatus);
 
         let voter_key = ctx.accounts.voter.key();
         let current_time = Clock::get()?.unix_timestamp;
+        paper.require_voting_open(current_time)?;
+        let power = escrow_voting_power(ctx.accounts.vote_escrow.as_deref(), paper, current_time)?;
+        let vote_weight = match program_state.voting_mode {
+            VotingMode::Linear => {
+                require!(credits == 0, ResearchError::InvalidAmount);
+                program_state.vote_weight(power)
+            }
+            VotingMode::Quadratic => {
+                require!(credits > 0, ResearchError::InvalidAmount);
+                let voter_credits = ctx
+                    .accounts
+                    .voter_credits
+                    .as_mut()
+                    .ok_or(ResearchError::VoteCreditsRequired)?;
+                let spent = voter_credits.spent.checked_add(credits).unwrap();
+                require!(
+                    spent <= program_state.vote_credits(power),
+                    ResearchError::InsufficientVoteCredits
+                );
 
-        // Get voter token balance for weighted voting
-        let voter_token_data = ctx.accounts.voter_token_account.try_borrow_data()?;
-        let voter_balance = if voter_token_data.len() >= 72 {
-            u64::from_le_bytes([
-                voter_token_data[64], voter_token_data[65], voter_token_data[66], voter_token_data[67],
-                voter_token_data[68], voter_token_data[69], voter_token_data[70], voter_token_data[71],
-            ])
-        } else {
-            0 // Default to 0 if account data is insufficient
+                voter_credits.voter = voter_key;
+                voter_credits.epoch = program_state.vote_epoch(current_time);
+                voter_credits.spent = spent;
+                voter_credits.bump = ctx.bumps.voter_credits.unwrap();
+                credits.isqrt()
+            }
         };
 
-        let vote_weight = if voter_balance > 0 { 
-            std::cmp::min(voter_balance / 1_000_000, 10) // Max 10x weight
-        } else { 1 };
-
         // Create vote record
         let vote = &mut ctx.accounts.vote;
-        vote.paper_id = paper_id;
+        vote.paper_id = paper.id;
         vote.voter = voter_key;
         vote.is_upvote = is_upvote;
         vote.weight = vote_weight;
         vote.timestamp = current_time;
         vote.bump = ctx.bumps.vote;
+        vote.credits = credits;
 
-        // Update vote counts
-        if is_upvote {
-            paper.upvotes = paper.upvotes.checked_add(vote_weight).unwrap();
-        } else {
-            paper.downvotes = paper.downvotes.checked_add(vote_weight).unwrap();
-        }
-
+        paper.add_vote(is_upvote, vote_weight);
         paper.updated_at = current_time;
 
         emit!(PaperVotedEvent {
@@ -237,6 +764,76 @@ pub mod biox_research {
             voter: voter_key,
             is_upvote,
             weight: vote_weight,
+            credits,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Flip or re-cast an existing vote. Quadratic votes keep the weight their credits bought;
+    /// other votes are recomputed from the voter's current escrowed voting power.
+    pub fn change_vote(ctx: Context<ChangeVote>, is_upvote: bool) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_VOTING), ResearchError::ProgramPaused);
+
+        let paper = &mut ctx.accounts.paper;
+        require!(paper.status.is_published(), ResearchError::PaperNotPublished);
+        require!(paper.status != PaperStatus::Retracted, ResearchError::PaperRetracted);
+        require!(!paper.status.is_final(), ResearchError::InvalidPaperStatus);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        paper.require_voting_open(current_time)?;
+        let vote = &mut ctx.accounts.vote;
+        let vote_weight = if vote.credits > 0 {
+            vote.weight
+        } else {
+            let power = escrow_voting_power(ctx.accounts.vote_escrow.as_deref(), paper, current_time)?;
+            program_state.vote_weight(power)
+        };
+        let previous_is_upvote = vote.is_upvote;
+        let previous_weight = vote.weight;
+        paper.remove_vote(previous_is_upvote, previous_weight);
+        paper.add_vote(is_upvote, vote_weight);
+        paper.updated_at = current_time;
+
+        vote.is_upvote = is_upvote;
+        vote.weight = vote_weight;
+        vote.timestamp = current_time;
+
+        emit!(VoteChangedEvent {
+            paper_id: paper.id,
+            voter: vote.voter,
+            previous_is_upvote,
+            previous_weight,
+            is_upvote,
+            weight: vote_weight,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw a vote, removing its weight from the paper and returning rent to the voter
+    pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(!program_state.is_paused(ProgramState::PAUSE_VOTING), ResearchError::ProgramPaused);
+
+        let paper = &mut ctx.accounts.paper;
+        require!(paper.status != PaperStatus::Retracted, ResearchError::PaperRetracted);
+        require!(!paper.status.is_final(), ResearchError::InvalidPaperStatus);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        paper.require_voting_open(current_time)?;
+        let vote = &ctx.accounts.vote;
+        paper.remove_vote(vote.is_upvote, vote.weight);
+        paper.updated_at = current_time;
+
+        emit!(VoteRetractedEvent {
+            paper_id: paper.id,
+            voter: vote.voter,
+            is_upvote: vote.is_upvote,
+            weight: vote.weight,
             timestamp: current_time,
         });
 
@@ -244,11 +841,13 @@ pub mod biox_research {
     }
 
     /// Claim funds (only by author when fully funded)
-    pub fn claim_funds(ctx: Context<ClaimFunds>, paper_id: u64) -> Result<()> {
+    pub fn claim_funds(ctx: Context<ClaimFunds>) -> Result<()> {
         let program_state = &ctx.accounts.program_state;
-        require!(!program_state.is_paused, ResearchError::ProgramPaused);
+        require!(!program_state.is_paused(ProgramState::PAUSE_CLAIMS), ResearchError::ProgramPaused);
 
+        let paper_key = ctx.accounts.paper.key();
         let paper = &mut ctx.accounts.paper;
+        let escrow_seed = paper.child_seed(&paper_key);
         require!(paper.author == ctx.accounts.author.key(), ResearchError::Unauthorized);
         require!(paper.status == PaperStatus::FullyFunded, ResearchError::NotFullyFunded);
 
@@ -272,15 +871,23 @@ pub mod biox_research {
                 },
                 &[&[
                     b"paper-token", 
-                    paper_id.to_le_bytes().as_ref(), 
+                    escrow_seed.as_ref(), 
                     &[ctx.bumps.paper_token_account]
                 ]],
             ),
             paper_token_balance,
         )?;
 
-        paper.status = PaperStatus::Completed;
-        paper.updated_at = Clock::get()?.unix_timestamp;
+        paper.transition(PaperStatus::Completed, Clock::get()?.unix_timestamp)?;
+
+        let profile = &mut ctx.accounts.author_profile;
+        profile.initialize_if_new(paper.author, paper.updated_at, ctx.bumps.author_profile);
+        profile.papers_completed = profile.papers_completed.checked_add(1).unwrap();
+        profile.reputation = profile
+            .reputation
+            .checked_add(ResearcherProfile::REPUTATION_PER_COMPLETION)
+            .unwrap();
+        profile.updated_at = paper.updated_at;
 
         emit!(FundsClaimedEvent {
             paper_id: paper.id,
@@ -292,376 +899,3679 @@ pub mod biox_research {
         Ok(())
     }
 
-    /// Emergency pause (admin only)
-    pub fn toggle_pause(ctx: Context<TogglePause>) -> Result<()> {
-        let program_state = &mut ctx.accounts.program_state;
-        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
-        
-        program_state.is_paused = !program_state.is_paused;
-        
-        emit!(PauseToggledEvent {
-            is_paused: program_state.is_paused,
-            timestamp: Clock::get()?.unix_timestamp,
+    /// Expire a published paper whose funding deadline has passed, opening refunds to its funders.
+    /// Permissionless so that keepers can crank it.
+    pub fn expire_paper(ctx: Context<ExpirePaper>) -> Result<()> {
+        let paper = &mut ctx.accounts.paper;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time > paper.funding_deadline, ResearchError::FundingDeadlineNotPassed);
+
+        paper.transition(PaperStatus::Expired, current_time)
+    }
+
+    /// Snapshot a paper's vote tally once its voting window has closed. Permissionless so that
+    /// keepers can crank it; the snapshot account can only be created once.
+    pub fn finalize_votes(ctx: Context<FinalizeVotes>) -> Result<()> {
+        let paper = &ctx.accounts.paper;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(paper.voting_closes_at != 0, ResearchError::PaperNotPublished);
+        require!(current_time >= paper.voting_closes_at, ResearchError::VotingNotClosed);
+
+        let tally = &mut ctx.accounts.vote_tally;
+        tally.paper = paper.key();
+        tally.paper_id = paper.id;
+        tally.upvotes = paper.upvotes;
+        tally.downvotes = paper.downvotes;
+        tally.voting_opens_at = paper.voting_opens_at;
+        tally.voting_closes_at = paper.voting_closes_at;
+        tally.finalized_at = current_time;
+        tally.bump = ctx.bumps.vote_tally;
+
+        emit!(VoteTallyFinalizedEvent {
+            paper_id: paper.id,
+            upvotes: paper.upvotes,
+            downvotes: paper.downvotes,
+            voting_opens_at: paper.voting_opens_at,
+            voting_closes_at: paper.voting_closes_at,
+            timestamp: current_time,
         });
 
         Ok(())
     }
 
-    /// Update platform settings (admin only)
-    pub fn update_settings(
-        ctx: Context<UpdateSettings>, 
-        platform_fee_rate: Option<u16>,
-        min_funding_goal: Option<u64>
-    ) -> Result<()> {
+    /// Return a funder's contribution from an expired or retracted paper and close their funding
+    /// record. The platform fee is not refunded, and neither are funds the author already claimed.
+    pub fn refund_funding(ctx: Context<RefundFunding>) -> Result<()> {
+        require!(
+            !ctx.accounts.program_state.is_paused(ProgramState::PAUSE_REFUNDS),
+            ResearchError::ProgramPaused
+        );
+
+        let paper_key = ctx.accounts.paper.key();
+        let paper = &mut ctx.accounts.paper;
+        let escrow_seed = paper.child_seed(&paper_key);
+        match paper.status {
+            PaperStatus::Expired => {}
+            PaperStatus::Retracted => require!(
+                !retracted_after_claim(ctx.accounts.retraction.as_deref())?,
+                ResearchError::FundsAlreadyClaimed
+            ),
+            _ => return err!(ResearchError::InvalidPaperStatus),
+        }
+
+        let amount = ctx.accounts.funding.amount;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.paper_token_account.to_account_info(),
+                    to: ctx.accounts.funder_token_account.to_account_info(),
+                    authority: ctx.accounts.paper_token_account.to_account_info(),
+                },
+                &[&[
+                    b"paper-token",
+                    escrow_seed.as_ref(),
+                    &[ctx.bumps.paper_token_account]
+                ]],
+            ),
+            amount,
+        )?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        paper.funding_current = paper.funding_current.checked_sub(amount).unwrap();
+
+        let program_state = &mut ctx.accounts.program_state;
+        program_state.total_funding = program_state.total_funding.checked_sub(amount).unwrap();
+
+        // Funding from before profiles tracked it was never counted, so the total can't go negative
+        let profile = &mut ctx.accounts.author_profile;
+        profile.initialize_if_new(paper.author, current_time, ctx.bumps.author_profile);
+        profile.total_funding_raised = profile.total_funding_raised.saturating_sub(amount);
+        profile.updated_at = current_time;
+
+        emit!(FundingRefundedEvent {
+            paper_id: paper.id,
+            funder: ctx.accounts.funder.key(),
+            amount,
+            remaining_funding: paper.funding_current,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Create or update the caller's researcher profile. Profiles are also created
+    /// automatically on first submission; stats are maintained by the program.
+    pub fn update_profile(
+        ctx: Context<UpdateProfile>,
+        display_name: String,
+        affiliation: String,
+        orcid: String,
+        bio_cid: String,
+    ) -> Result<()> {
+        ResearcherProfile::validate_display_name(&display_name)?;
+        ResearcherProfile::validate_affiliation(&affiliation)?;
+        ResearcherProfile::validate_orcid(&orcid)?;
+        let bio_cid = ContentId::parse_optional(&bio_cid)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let profile = &mut ctx.accounts.profile;
+        profile.initialize_if_new(ctx.accounts.wallet.key(), current_time, ctx.bumps.profile);
+        profile.display_name = display_name;
+        profile.affiliation = affiliation;
+        profile.orcid = orcid;
+        profile.bio_cid = bio_cid;
+        profile.updated_at = current_time;
+
+        emit!(ProfileUpdatedEvent {
+            wallet: profile.wallet,
+            display_name: profile.display_name.clone(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Retract a published paper (author, admin or editor) with a public retraction notice
+    pub fn retract_paper(
+        ctx: Context<RetractPaper>,
+        reason: RetractionReason,
+        notice_cid: String,
+    ) -> Result<()> {
+        let paper = &mut ctx.accounts.paper;
+        let authority = ctx.accounts.authority.key();
+
+        // A present editor account is seeded by the authority, so it proves the editor role
+        require!(
+            paper.author == authority
+                || ctx.accounts.program_state.admin == authority
+                || ctx.accounts.editor.is_some(),
+            ResearchError::Unauthorized
+        );
+        let notice_cid = ContentId::parse(&notice_cid)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let previous_status = paper.status;
+        paper.transition(PaperStatus::Retracted, current_time)?;
+
+        let retraction = &mut ctx.accounts.retraction;
+        retraction.paper_id = paper.id;
+        retraction.retracted_by = authority;
+        retraction.reason = reason;
+        retraction.notice_cid = notice_cid;
+        retraction.previous_status = previous_status;
+        retraction.timestamp = current_time;
+        retraction.bump = ctx.bumps.retraction;
+
+        emit!(PaperRetractedEvent {
+            paper_id: paper.id,
+            author: paper.author,
+            retracted_by: authority,
+            reason,
+            notice_cid,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Close a funding record once its paper is settled, retracted after its funds were claimed,
+    /// or closed, returning rent to the funder
+    pub fn close_funding(ctx: Context<CloseFunding>) -> Result<()> {
+        if let Some(paper) = load_paper(&ctx.accounts.paper)? {
+            require!(
+                paper.status.is_final()
+                    || (paper.status == PaperStatus::Retracted
+                        && retracted_after_claim(ctx.accounts.retraction.as_deref())?),
+                ResearchError::InvalidPaperStatus
+            );
+        }
+
+        let funding = &ctx.accounts.funding;
+        emit!(FundingClosedEvent {
+            paper: ctx.accounts.paper.key(),
+            paper_id: funding.paper_id,
+            funder: funding.funder,
+            amount: funding.amount,
+            platform_fee: funding.platform_fee,
+            funded_at: funding.timestamp,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Close a vote record once its paper is settled or retracted and voting has closed, or once
+    /// the paper is closed, returning rent to the voter. The vote's weight stays in the final
+    /// tally.
+    pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+        if let Some(paper) = load_paper(&ctx.accounts.paper)? {
+            require!(
+                paper.status.is_final() || paper.status == PaperStatus::Retracted,
+                ResearchError::InvalidPaperStatus
+            );
+            require!(
+                Clock::get()?.unix_timestamp >= paper.voting_closes_at,
+                ResearchError::VotingNotClosed
+            );
+        }
+
+        let vote = &ctx.accounts.vote;
+        emit!(VoteClosedEvent {
+            paper: ctx.accounts.paper.key(),
+            paper_id: vote.paper_id,
+            voter: vote.voter,
+            is_upvote: vote.is_upvote,
+            weight: vote.weight,
+            voted_at: vote.timestamp,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Close a settled paper and its metadata (author only), returning rent to the author. The closing event
+    /// carries the paper's final tallies so its history stays reconstructable from logs.
+    /// Expired and retracted papers can be closed once every funder has been refunded, and papers
+    /// retracted after their funds were claimed at any time.
+    pub fn close_paper(ctx: Context<ClosePaper>) -> Result<()> {
+        let paper = &ctx.accounts.paper;
+        require!(paper.author == ctx.accounts.author.key(), ResearchError::Unauthorized);
+        let settled = match paper.status {
+            PaperStatus::Expired => paper.funding_current == 0,
+            PaperStatus::Retracted => {
+                paper.funding_current == 0
+                    || retracted_after_claim(ctx.accounts.retraction.as_deref())?
+            }
+            status => status.is_final(),
+        };
+        require!(settled, ResearchError::InvalidPaperStatus);
+
+        emit!(PaperClosedEvent {
+            paper: paper.key(),
+            paper_id: paper.id,
+            author: paper.author,
+            status: paper.status,
+            content_id: paper.content_id,
+            manuscript_version: paper.manuscript_version,
+            funding_goal: paper.funding_goal,
+            funding_current: paper.funding_current,
+            upvotes: paper.upvotes,
+            downvotes: paper.downvotes,
+            citations_in: paper.citations_in,
+            citations_out: paper.citations_out,
+            created_at: paper.created_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Grant the editor role (admin only)
+    pub fn add_editor(ctx: Context<AddEditor>, editor: Pubkey) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let editor_account = &mut ctx.accounts.editor_account;
+        editor_account.editor = editor;
+        editor_account.added_by = ctx.accounts.admin.key();
+        editor_account.added_at = current_time;
+        editor_account.bump = ctx.bumps.editor_account;
+
+        emit!(EditorAddedEvent {
+            editor,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Revoke the editor role (admin only)
+    pub fn remove_editor(ctx: Context<RemoveEditor>, editor: Pubkey) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+
+        emit!(EditorRemovedEvent {
+            editor,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Emergency pause (admin only). Toggles every instruction family except
+    /// claims and refunds, so funded authors and funders are never trapped.
+    pub fn toggle_pause(ctx: Context<TogglePause>) -> Result<()> {
+        let program_state = &mut ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        
+        let previous_flags = program_state.pause_flags;
+        if previous_flags & ProgramState::PAUSE_EMERGENCY != 0 {
+            program_state.pause_flags &= !ProgramState::PAUSE_EMERGENCY;
+        } else {
+            program_state.pause_flags |= ProgramState::PAUSE_EMERGENCY;
+        }
+        
+        emit_pause_flag_changes(previous_flags, program_state.pause_flags, Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+
+    /// Pause one or more instruction families (admin only)
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, flags: u8) -> Result<()> {
+        let program_state = &mut ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        require!(
+            flags != 0 && flags & !ProgramState::PAUSE_ALL == 0,
+            ResearchError::InvalidPauseFlags
+        );
+
+        let previous_flags = program_state.pause_flags;
+        program_state.pause_flags |= flags;
+
+        emit_pause_flag_changes(previous_flags, program_state.pause_flags, Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+
+    /// Unpause one or more instruction families (admin only)
+    pub fn clear_pause_flags(ctx: Context<ClearPauseFlags>, flags: u8) -> Result<()> {
+        let program_state = &mut ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        require!(
+            flags != 0 && flags & !ProgramState::PAUSE_ALL == 0,
+            ResearchError::InvalidPauseFlags
+        );
+
+        let previous_flags = program_state.pause_flags;
+        program_state.pause_flags &= !flags;
+
+        emit_pause_flag_changes(previous_flags, program_state.pause_flags, Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+
+    /// Queue a platform settings change (admin only). It takes effect once
+    /// `settings_delay` has elapsed and someone calls `execute_settings_change`.
+    pub fn queue_settings_change(
+        ctx: Context<QueueSettingsChange>, 
+        platform_fee_rate: Option<u16>,
+        min_funding_goal: Option<u64>,
+        settings_delay: Option<i64>,
+        vote_weight: Option<VoteWeightFormula>,
+    ) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        require!(
+            platform_fee_rate.is_some()
+                || min_funding_goal.is_some()
+                || settings_delay.is_some()
+                || vote_weight.is_some(),
+            ResearchError::EmptySettingsChange
+        );
+
+        if let Some(fee_rate) = platform_fee_rate {
+            require!(fee_rate <= 1000, ResearchError::FeeTooHigh); // Max 10%
+        }
+
+        if let Some(delay) = settings_delay {
+            require!(
+                (0..=ProgramState::MAX_SETTINGS_DELAY).contains(&delay),
+                ResearchError::InvalidSettingsDelay
+            );
+        }
+
+        if let Some(formula) = vote_weight {
+            formula.validate()?;
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let effective_at = current_time + program_state.settings_delay;
+
+        let settings_change = &mut ctx.accounts.settings_change;
+        settings_change.platform_fee_rate = platform_fee_rate;
+        settings_change.min_funding_goal = min_funding_goal;
+        settings_change.settings_delay = settings_delay;
+        settings_change.vote_weight = vote_weight;
+        settings_change.proposed_by = ctx.accounts.admin.key();
+        settings_change.queued_at = current_time;
+        settings_change.effective_at = effective_at;
+        settings_change.bump = ctx.bumps.settings_change;
+
+        emit!(SettingsChangeQueuedEvent {
+            platform_fee_rate,
+            min_funding_goal,
+            settings_delay,
+            vote_weight,
+            queued_at: current_time,
+            effective_at,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a queued settings change (admin only)
+    pub fn cancel_settings_change(ctx: Context<CancelSettingsChange>) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+
+        emit!(SettingsChangeCancelledEvent {
+            admin: ctx.accounts.admin.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Apply a queued settings change once its delay has elapsed (anyone)
+    pub fn execute_settings_change(ctx: Context<ExecuteSettingsChange>) -> Result<()> {
+        let settings_change = &ctx.accounts.settings_change;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= settings_change.effective_at, ResearchError::SettingsChangeNotReady);
+
+        let program_state = &mut ctx.accounts.program_state;
+
+        if let Some(fee_rate) = settings_change.platform_fee_rate {
+            program_state.platform_fee_rate = fee_rate;
+        }
+
+        if let Some(min_goal) = settings_change.min_funding_goal {
+            program_state.min_funding_goal = min_goal;
+        }
+
+        if let Some(delay) = settings_change.settings_delay {
+            program_state.settings_delay = delay;
+        }
+
+        if let Some(formula) = settings_change.vote_weight {
+            program_state.set_vote_weight_formula(formula);
+        }
+
+        emit!(SettingsChangeExecutedEvent {
+            platform_fee_rate: program_state.platform_fee_rate,
+            min_funding_goal: program_state.min_funding_goal,
+            settings_delay: program_state.settings_delay,
+            vote_weight: program_state.vote_weight_formula(),
+            executed_by: ctx.accounts.executor.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Propose a new admin (admin only). The proposed key must accept before it takes effect.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
         let program_state = &mut ctx.accounts.program_state;
         require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        require!(new_admin != program_state.admin, ResearchError::InvalidAdmin);
+
+        program_state.pending_admin = Some(new_admin);
+
+        emit!(AdminProposedEvent {
+            current_admin: program_state.admin,
+            proposed_admin: new_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal (proposed admin only)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let program_state = &mut ctx.accounts.program_state;
+        let pending_admin = program_state.pending_admin.ok_or(ResearchError::NoPendingAdmin)?;
+        require!(pending_admin == ctx.accounts.new_admin.key(), ResearchError::Unauthorized);
+
+        let previous_admin = program_state.admin;
+        program_state.admin = pending_admin;
+        program_state.pending_admin = None;
+
+        emit!(AdminAcceptedEvent {
+            previous_admin,
+            new_admin: pending_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a pending admin proposal (admin only)
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        let program_state = &mut ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        let cancelled_admin = program_state.pending_admin.ok_or(ResearchError::NoPendingAdmin)?;
+
+        program_state.pending_admin = None;
+
+        emit!(AdminProposalCancelledEvent {
+            admin: program_state.admin,
+            cancelled_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Upgrade the program state account to the current layout (admin only)
+    pub fn migrate_program_state(ctx: Context<MigrateProgramState>) -> Result<()> {
+        let program_state_info = ctx.accounts.program_state.to_account_info();
+        let new_len = 8 + ProgramState::SPACE;
+
+        // Fields added since the account was created decode from the zeroed tail
+        // and are filled in by `ProgramState::migrate`
+        if program_state_info.data_len() < new_len {
+            resize_account(
+                &program_state_info,
+                &ctx.accounts.admin.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                new_len,
+            )?;
+        }
+
+        let mut program_state = ProgramState::try_deserialize(&mut &program_state_info.try_borrow_data()?[..])?;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        require!(program_state.version < ProgramState::CURRENT_VERSION, ResearchError::AlreadyMigrated);
+
+        // Version 3 scales the vote weight divisor by the governance mint's decimals
+        if program_state.version < 3 && program_state.governance_mint != Pubkey::default() {
+            let governance_mint = ctx
+                .accounts
+                .governance_mint
+                .as_ref()
+                .ok_or(ResearchError::GovernanceMintRequired)?;
+            require!(
+                governance_mint.key() == program_state.governance_mint,
+                ResearchError::GovernanceMintRequired
+            );
+            program_state.governance_decimals = governance_mint.decimals;
+        }
+
+        let from_version = program_state.version;
+        program_state.migrate();
+        program_state.try_serialize(&mut &mut program_state_info.try_borrow_mut_data()?[..])?;
+
+        emit!(AccountMigratedEvent {
+            account: program_state_info.key(),
+            from_version,
+            to_version: program_state.version,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Upgrade a paper account to the current layout (author or admin), moving its strings
+    /// into a new metadata account
+    pub fn migrate_research_paper(ctx: Context<MigrateResearchPaper>) -> Result<()> {
+        let paper_info = ctx.accounts.paper.to_account_info();
+
+        // Only papers from before the metadata split lack a metadata account
+        let (from_version, mut paper) = if ctx.accounts.metadata.paper == Pubkey::default() {
+            let original_len = paper_info.data_len();
+
+            // Zero-extend so the fields appended since the paper was created can be decoded
+            paper_info.realloc(original_len + LegacyResearchPaper::MIGRATION_TAIL, true)?;
+
+            let mut legacy = LegacyResearchPaper::try_deserialize(&paper_info.try_borrow_data()?)?;
+            let from_version = legacy.version;
+            legacy.migrate();
+
+            let metadata = &mut ctx.accounts.metadata;
+            metadata.paper = paper_info.key();
+            metadata.bump = ctx.bumps.metadata;
+            metadata.title = std::mem::take(&mut legacy.title);
+            metadata.abstract_text = std::mem::take(&mut legacy.abstract_text);
+            metadata.authors = std::mem::take(&mut legacy.authors);
+            metadata.keywords = std::mem::take(&mut legacy.keywords);
+            let metadata_len = 8 + metadata.space();
+            resize_account(
+                &ctx.accounts.metadata.to_account_info(),
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                metadata_len,
+            )?;
+
+            (from_version, legacy.into_paper())
+        } else {
+            // Version 7 papers differ from the version 8 layout only by the `is_published` byte
+            if paper_info.data_len() == LegacyResearchPaper::V7_LEN {
+                let offset = LegacyResearchPaper::V7_IS_PUBLISHED_OFFSET;
+                let mut data = paper_info.try_borrow_mut_data()?;
+                data.copy_within(offset + 1.., offset);
+                data[LegacyResearchPaper::V7_LEN - 1] = 0;
+            }
+
+            // Zero-extend into the reserved tail that version 9 grew
+            if paper_info.data_len() < 8 + ResearchPaper::SPACE {
+                paper_info.realloc(8 + ResearchPaper::SPACE, true)?;
+            }
+
+            let paper = ResearchPaper::try_deserialize(&mut &paper_info.try_borrow_data()?[..])?;
+            require!(paper.version < ResearchPaper::CURRENT_VERSION, ResearchError::AlreadyMigrated);
+            (paper.version, paper)
+        };
+
+        let authority = ctx.accounts.authority.key();
+        require!(
+            paper.author == authority || ctx.accounts.program_state.admin == authority,
+            ResearchError::Unauthorized
+        );
+        paper.version = ResearchPaper::CURRENT_VERSION;
+
+        // Version 9 added voting windows; papers published before then get a full window from now
+        let current_time = Clock::get()?.unix_timestamp;
+        if from_version < 9 && paper.status.is_published() {
+            paper.open_voting(&ctx.accounts.program_state, current_time);
+        }
+
+        // Any rent freed by the old layout refunds the authority, who paid for new metadata
+        resize_account(
+            &paper_info,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + ResearchPaper::SPACE,
+        )?;
+        paper.try_serialize(&mut &mut paper_info.try_borrow_mut_data()?[..])?;
+
+        emit!(AccountMigratedEvent {
+            account: paper_info.key(),
+            from_version,
+            to_version: paper.version,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Append the `credits` field to a vote cast before quadratic voting (voter only). Its
+    /// credits are zero, so it keeps its escrow-derived weight.
+    pub fn migrate_vote(ctx: Context<MigrateVote>) -> Result<()> {
+        let vote_info = ctx.accounts.vote.to_account_info();
+        require!(vote_info.data_len() == 8 + Vote::LEGACY_SPACE, ResearchError::AlreadyMigrated);
+
+        resize_account(
+            &vote_info,
+            &ctx.accounts.voter.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + Vote::SPACE,
+        )?;
+        let vote = Vote::try_deserialize(&mut &vote_info.try_borrow_data()?[..])?;
+        require!(vote.voter == ctx.accounts.voter.key(), ResearchError::Unauthorized);
+
+        Ok(())
+    }
+
+    /// Enable token governance with the BIOX mint and its escrow vault, which also holds
+    /// vote-escrow locks (admin only, once)
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        quorum: u64,
+        threshold_bps: u16,
+        voting_period: i64,
+    ) -> Result<()> {
+        let program_state = &mut ctx.accounts.program_state;
+        require!(program_state.admin == ctx.accounts.admin.key(), ResearchError::Unauthorized);
+        require!(
+            program_state.governance_mint == Pubkey::default(),
+            ResearchError::GovernanceAlreadyInitialized
+        );
+
+        ParameterChange::GovernanceQuorum(quorum).validate(program_state)?;
+        ParameterChange::GovernanceThreshold(threshold_bps).validate(program_state)?;
+        ParameterChange::GovernanceVotingPeriod(voting_period).validate(program_state)?;
+
+        program_state.governance_mint = ctx.accounts.governance_mint.key();
+        program_state.governance_decimals = ctx.accounts.governance_mint.decimals;
+        program_state.governance_quorum = quorum;
+        program_state.governance_threshold_bps = threshold_bps;
+        program_state.governance_voting_period = voting_period;
+
+        Ok(())
+    }
+
+    /// Create a governance proposal to change one platform parameter (BIOX holders)
+    pub fn create_proposal(ctx: Context<CreateProposal>, change: ParameterChange) -> Result<()> {
+        let program_state = &ctx.accounts.program_state;
+        require!(
+            program_state.governance_mint != Pubkey::default(),
+            ResearchError::GovernanceNotInitialized
+        );
+        require!(ctx.accounts.proposer_token_account.amount > 0, ResearchError::InsufficientGovernanceTokens);
+        change.validate(program_state)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let voting_ends_at = current_time + program_state.governance_voting_period;
+
+        let program_state = &mut ctx.accounts.program_state;
+        let proposal_id = program_state.proposal_count;
+        program_state.proposal_count += 1;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.id = proposal_id;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.change = change;
+        proposal.votes_for = 0;
+        proposal.votes_against = 0;
+        proposal.created_at = current_time;
+        proposal.voting_ends_at = voting_ends_at;
+        proposal.executed = false;
+        proposal.bump = ctx.bumps.proposal;
+
+        emit!(ProposalCreatedEvent {
+            proposal_id,
+            proposer: proposal.proposer,
+            change,
+            voting_ends_at,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Vote on a proposal by escrowing BIOX until voting ends
+    pub fn cast_governance_vote(
+        ctx: Context<CastGovernanceVote>,
+        proposal_id: u64,
+        support: bool,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ResearchError::InvalidAmount);
+
+        let proposal = &mut ctx.accounts.proposal;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < proposal.voting_ends_at, ResearchError::ProposalVotingClosed);
+
+        // Lock the voting tokens so they cannot be moved and reused on this proposal
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.voter_token_account.to_account_info(),
+                    to: ctx.accounts.governance_vault.to_account_info(),
+                    authority: ctx.accounts.voter.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        if support {
+            proposal.votes_for = proposal.votes_for.checked_add(amount).unwrap();
+        } else {
+            proposal.votes_against = proposal.votes_against.checked_add(amount).unwrap();
+        }
+
+        let governance_vote = &mut ctx.accounts.governance_vote;
+        governance_vote.proposal_id = proposal_id;
+        governance_vote.voter = ctx.accounts.voter.key();
+        governance_vote.support = support;
+        governance_vote.amount = amount;
+        governance_vote.timestamp = current_time;
+        governance_vote.bump = ctx.bumps.governance_vote;
+
+        emit!(GovernanceVoteCastEvent {
+            proposal_id,
+            voter: governance_vote.voter,
+            support,
+            amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Return escrowed voting tokens once a proposal's voting period has ended
+    pub fn withdraw_governance_vote(ctx: Context<WithdrawGovernanceVote>, proposal_id: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= ctx.accounts.proposal.voting_ends_at,
+            ResearchError::ProposalVotingActive
+        );
+
+        let amount = ctx.accounts.governance_vote.amount;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.governance_vault.to_account_info(),
+                    to: ctx.accounts.voter_token_account.to_account_info(),
+                    authority: ctx.accounts.governance_vault.to_account_info(),
+                },
+                &[&[b"governance-vault", &[ctx.bumps.governance_vault]]],
+            ),
+            amount,
+        )?;
+
+        emit!(GovernanceVoteWithdrawnEvent {
+            proposal_id,
+            voter: ctx.accounts.voter.key(),
+            amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Apply a proposal that met quorum and passed its threshold once voting has ended (anyone).
+    /// Unlike admin settings changes this skips the settings timelock: the proposal has been
+    /// public for its whole voting period, which is the notice the timelock exists to give.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let program_state = &mut ctx.accounts.program_state;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!proposal.executed, ResearchError::ProposalAlreadyExecuted);
+        require!(current_time >= proposal.voting_ends_at, ResearchError::ProposalVotingActive);
+
+        let total_votes = proposal.votes_for.checked_add(proposal.votes_against).unwrap();
+        require!(total_votes >= program_state.governance_quorum, ResearchError::QuorumNotReached);
+        require!(
+            proposal.votes_for as u128 * 10000
+                > total_votes as u128 * program_state.governance_threshold_bps as u128,
+            ResearchError::ProposalNotPassed
+        );
+
+        // Parameters may have been tightened since creation
+        proposal.change.validate(program_state)?;
+        proposal.change.apply(program_state);
+        proposal.executed = true;
+
+        emit!(ProposalExecutedEvent {
+            proposal_id,
+            change: proposal.change,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+            executed_by: ctx.accounts.executor.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
+
+/// Emit one `PauseFlagChangedEvent` per flag that differs between `previous` and `current`
+fn emit_pause_flag_changes(previous: u8, current: u8, timestamp: i64) {
+    let changed = previous ^ current;
+    for bit in 0..8 {
+        let flag = 1u8 << bit;
+        if changed & flag != 0 {
+            emit!(PauseFlagChangedEvent {
+                flag,
+                is_paused: current & flag != 0,
+                pause_flags: current,
+                timestamp,
+            });
+        }
+    }
+}
+
+/// The voter's escrowed voting power, or zero without an escrow. The lock must outlast the
+/// paper's voting window, or its tokens could be withdrawn and voted again, and is recorded
+/// on the paper so the window can't later be extended past it.
+fn escrow_voting_power(escrow: Option<&VoteEscrow>, paper: &mut ResearchPaper, now: i64) -> Result<u64> {
+    match escrow {
+        Some(escrow) => {
+            require!(escrow.unlock_at >= paper.voting_closes_at, ResearchError::LockTooShort);
+            paper.record_lock(escrow.unlock_at);
+            Ok(escrow.voting_power(now))
+        }
+        None => Ok(0),
+    }
+}
+
+/// Whether a retracted paper was retracted after its author claimed the escrow, leaving its
+/// funders nothing to refund
+fn retracted_after_claim(retraction: Option<&Retraction>) -> Result<bool> {
+    let retraction = retraction.ok_or(ResearchError::RetractionRequired)?;
+    Ok(retraction.previous_status == PaperStatus::Completed)
+}
+
+/// The paper behind a funding or vote record, or `None` once the paper account has been closed
+fn load_paper(paper: &AccountInfo) -> Result<Option<ResearchPaper>> {
+    if paper.data_is_empty() {
+        return Ok(None);
+    }
+    require!(paper.owner == &crate::ID, ResearchError::InvalidPaperStatus);
+    let paper = ResearchPaper::try_deserialize(&mut &paper.try_borrow_data()?[..])?;
+    Ok(Some(paper))
+}
+
+/// Seed of a possibly closed paper's child accounts; see `ResearchPaper::child_seed`. Once the
+/// paper is closed only child accounts seeded by its address can be found.
+///
+/// Seeds computed at runtime are sliced (`&expr[..]`) or called as associated functions in
+/// account constraints: the IDL build cannot resolve them and leaves such accounts for the
+/// client to pass, where it would otherwise emit a wrong seed path or try to evaluate them as
+/// constants.
+fn paper_child_seed(paper: &AccountInfo) -> Result<Vec<u8>> {
+    Ok(match load_paper(paper)? {
+        Some(paper_data) => paper_data.child_seed(paper.key),
+        None => paper.key().to_bytes().to_vec(),
+    })
+}
+
+/// Close the registry entry `paper` holds for its replaced manuscript, returning rent to
+/// `recipient` so the manuscript can be submitted again. Papers migrated from before the
+/// registry may hold no entry, or share their manuscript with a paper that registered it.
+fn release_manuscript<'info>(
+    registry: &AccountInfo<'info>,
+    paper: &Pubkey,
+    recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    if registry.data_is_empty() {
+        return Ok(());
+    }
+    require!(
+        registry.owner == &crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    let entry = ManuscriptRegistry::try_deserialize(&mut &registry.try_borrow_data()?[..])?;
+    if entry.paper == *paper {
+        **recipient.try_borrow_mut_lamports()? += registry.lamports();
+        **registry.try_borrow_mut_lamports()? = 0;
+        registry.assign(&system_program::ID);
+        registry.realloc(0, false)?;
+    }
+    Ok(())
+}
+
+/// Resize a program-owned account, charging `payer` for extra rent or refunding any excess to it
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account.lamports();
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    } else if current_lamports > required_lamports {
+        let excess = current_lamports - required_lamports;
+        **account.try_borrow_mut_lamports()? -= excess;
+        **payer.try_borrow_mut_lamports()? += excess;
+    }
+
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+// Account validation structs
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProgramState::SPACE,
+        seeds = [b"program-state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    title: String,
+    abstract_text: String,
+    ipfs_hash: String,
+    authors: Vec<String>,
+    funding_goal: u64,
+    funding_period_days: u64,
+    category: ResearchCategory,
+    keywords: Vec<String>
+)]
+pub struct SubmitPaper<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        space = 8 + AuthorIndex::SPACE,
+        seeds = [b"author-index", author.key().as_ref()],
+        bump
+    )]
+    pub author_index: Account<'info, AuthorIndex>,
+    
+    #[account(
+        init,
+        payer = author,
+        space = 8 + ResearchPaper::SPACE,
+        seeds = [b"paper", author.key().as_ref(), author_index.paper_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + PaperMetadata::space_for(&title, &abstract_text, &authors, &keywords),
+        seeds = [b"paper-metadata", paper.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, PaperMetadata>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        space = 8 + ManuscriptRegistry::SPACE,
+        seeds = [b"manuscript", &ContentId::parse(&ipfs_hash)?.digest[..]],
+        bump
+    )]
+    pub manuscript_registry: Account<'info, ManuscriptRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        space = 8 + ResearcherProfile::SPACE,
+        seeds = [b"researcher", author.key().as_ref()],
+        bump
+    )]
+    pub author_profile: Account<'info, ResearcherProfile>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    title: Option<String>,
+    abstract_text: Option<String>,
+    ipfs_hash: Option<String>
+)]
+pub struct UpdatePaper<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [b"paper-metadata", paper.key().as_ref()],
+        bump = metadata.bump
+    )]
+    pub metadata: Account<'info, PaperMetadata>,
+
+    /// Required when `ipfs_hash` changes, to register the new manuscript
+    #[account(
+        init_if_needed,
+        payer = author,
+        space = 8 + ManuscriptRegistry::SPACE,
+        seeds = [
+            b"manuscript",
+            &ContentId::parse(ipfs_hash.as_deref().unwrap_or_default())?.digest[..]
+        ],
+        bump
+    )]
+    pub manuscript_registry: Option<Account<'info, ManuscriptRegistry>>,
+
+    /// CHECK: Required when `ipfs_hash` changes, to release the replaced manuscript; may be
+    /// empty for papers migrated from before the registry
+    #[account(
+        mut,
+        seeds = [b"manuscript", paper.content_id.digest.as_ref()],
+        bump
+    )]
+    pub previous_manuscript_registry: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PublishPaper<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CategoryIndex::SPACE,
+        seeds = [b"category", &[paper.category as u8][..]],
+        bump
+    )]
+    pub category_index: Account<'info, CategoryIndex>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CategoryIndexPage::SPACE,
+        seeds = [
+            b"category-page",
+            &[paper.category as u8][..],
+            category_index.current_page().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub category_page: Account<'info, CategoryIndexPage>,
+
+    /// Created for authors of papers from before profiles existed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ResearcherProfile::SPACE,
+        seeds = [b"researcher", paper.author.as_ref()],
+        bump
+    )]
+    pub author_profile: Account<'info, ResearcherProfile>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewPaper<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct SetVotingWindow<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct PublishRevision<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + PaperRevision::SPACE,
+        seeds = [
+            b"revision",
+            &paper.child_seed(&paper.key())[..],
+            (paper.manuscript_version + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub revision: Account<'info, PaperRevision>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddCitation<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&citing_paper), citing_paper.nonce.to_le_bytes().as_ref()],
+        bump = citing_paper.bump
+    )]
+    pub citing_paper: Account<'info, ResearchPaper>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&cited_paper), cited_paper.nonce.to_le_bytes().as_ref()],
+        bump = cited_paper.bump
+    )]
+    pub cited_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + Citation::SPACE,
+        seeds = [
+            b"citation",
+            &citing_paper.child_seed(&citing_paper.key())[..],
+            &cited_paper.child_seed(&cited_paper.key())[..]
+        ],
+        bump
+    )]
+    pub citation: Account<'info, Citation>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(doi: String)]
+pub struct AddExternalCitation<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&citing_paper), citing_paper.nonce.to_le_bytes().as_ref()],
+        bump = citing_paper.bump
+    )]
+    pub citing_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + Citation::SPACE + doi.len(),
+        seeds = [
+            b"citation-doi",
+            &citing_paper.child_seed(&citing_paper.key())[..],
+            &hash(doi.as_bytes()).to_bytes()[..]
+        ],
+        bump
+    )]
+    pub citation: Account<'info, Citation>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct CommitPriority<'info> {
+    #[account(mut)]
+    pub committer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = committer,
+        space = 8 + PriorityCommitment::SPACE,
+        seeds = [b"priority", committer.key().as_ref(), commitment.as_ref()],
+        bump
+    )]
+    pub priority_commitment: Account<'info, PriorityCommitment>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealPriority<'info> {
+    pub committer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"priority",
+            priority_commitment.committer.as_ref(),
+            priority_commitment.commitment.as_ref()
+        ],
+        bump = priority_commitment.bump
+    )]
+    pub priority_commitment: Account<'info, PriorityCommitment>,
+
+    #[account(
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+}
+
+#[derive(Accounts)]
+pub struct FundPaper<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+    
+    /// CHECK: Token account validation handled manually
+    #[account(mut)]
+    pub funder_token_account: AccountInfo<'info>,
+    
+    /// CHECK: Token account validation handled manually
+    #[account(
+        mut,
+        seeds = [b"paper-token", &paper.child_seed(&paper.key())[..]],
+        bump,
+    )]
+    pub paper_token_account: AccountInfo<'info>,
+
+    /// CHECK: Token account validation handled manually
+    #[account(
+        mut,
+        seeds = [b"platform-vault"],
+        bump,
+    )]
+    pub platform_token_account: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = funder,
+        space = 8 + Funding::SPACE,
+        seeds = [b"funding", &paper.child_seed(&paper.key())[..], funder.key().as_ref()],
+        bump
+    )]
+    pub funding: Account<'info, Funding>,
+
+    /// Created for authors of papers from before profiles existed
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + ResearcherProfile::SPACE,
+        seeds = [b"researcher", paper.author.as_ref()],
+        bump
+    )]
+    pub author_profile: Account<'info, ResearcherProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LockTokens<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VoteEscrow::SPACE,
+        seeds = [b"vote-escrow", owner.key().as_ref()],
+        bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    #[account(
+        mut,
+        token::mint = program_state.governance_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"governance-vault"],
+        bump,
+    )]
+    pub governance_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLock<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"vote-escrow", owner.key().as_ref()],
+        bump = vote_escrow.bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    #[account(
+        mut,
+        token::mint = program_state.governance_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"governance-vault"],
+        bump,
+    )]
+    pub governance_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct VotePaper<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [b"vote-escrow", voter.key().as_ref()],
+        bump = vote_escrow.bump
+    )]
+    pub vote_escrow: Option<Account<'info, VoteEscrow>>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoterCredits::SPACE,
+        seeds = [
+            b"vote-credits",
+            voter.key().as_ref(),
+            program_state.vote_epoch(Clock::get()?.unix_timestamp).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub voter_credits: Option<Account<'info, VoterCredits>>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + Vote::SPACE,
+        seeds = [b"vote", &paper.child_seed(&paper.key())[..], voter.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [b"vote-escrow", voter.key().as_ref()],
+        bump = vote_escrow.bump
+    )]
+    pub vote_escrow: Option<Account<'info, VoteEscrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"vote", &paper.child_seed(&paper.key())[..], voter.key().as_ref()],
+        bump = vote.bump
+    )]
+    pub vote: Account<'info, Vote>,
+}
+
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote", &paper.child_seed(&paper.key())[..], voter.key().as_ref()],
+        bump = vote.bump
+    )]
+    pub vote: Account<'info, Vote>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFunds<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump,
+        constraint = paper.author == author.key()
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+    
+    /// CHECK: Token account validation handled manually
+    #[account(
+        mut,
+        seeds = [b"paper-token", &paper.child_seed(&paper.key())[..]],
+        bump,
+    )]
+    pub paper_token_account: AccountInfo<'info>,
+    
+    /// CHECK: Token account validation handled manually
+    #[account(mut)]
+    pub author_token_account: AccountInfo<'info>,
+
+    /// Created for authors of papers from before profiles existed
+    #[account(
+        init_if_needed,
+        payer = author,
+        space = 8 + ResearcherProfile::SPACE,
+        seeds = [b"researcher", paper.author.as_ref()],
+        bump
+    )]
+    pub author_profile: Account<'info, ResearcherProfile>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpirePaper<'info> {
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeVotes<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VoteTally::SPACE,
+        seeds = [b"vote-tally", paper.key().as_ref()],
+        bump
+    )]
+    pub vote_tally: Account<'info, VoteTally>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundFunding<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"funding", &paper.child_seed(&paper.key())[..], funder.key().as_ref()],
+        bump = funding.bump
+    )]
+    pub funding: Account<'info, Funding>,
+
+    /// CHECK: Token account validation handled manually
+    #[account(
+        mut,
+        seeds = [b"paper-token", &paper.child_seed(&paper.key())[..]],
+        bump,
+    )]
+    pub paper_token_account: AccountInfo<'info>,
+
+    /// CHECK: Token account validation handled manually
+    #[account(mut)]
+    pub funder_token_account: AccountInfo<'info>,
+
+    /// Required when the paper is retracted, to tell whether its funds were already claimed
+    #[account(
+        seeds = [b"retraction", &paper.child_seed(&paper.key())[..]],
+        bump = retraction.bump
+    )]
+    pub retraction: Option<Account<'info, Retraction>>,
+
+    /// Created for authors of papers from before profiles existed
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + ResearcherProfile::SPACE,
+        seeds = [b"researcher", paper.author.as_ref()],
+        bump
+    )]
+    pub author_profile: Account<'info, ResearcherProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = 8 + ResearcherProfile::SPACE,
+        seeds = [b"researcher", wallet.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, ResearcherProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseFunding<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// CHECK: May already be closed; its status is checked in the handler while it exists
+    pub paper: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"funding", &paper_child_seed(&paper)?[..], funder.key().as_ref()],
+        bump = funding.bump
+    )]
+    pub funding: Account<'info, Funding>,
+
+    /// Required while the paper exists and is retracted
+    #[account(
+        seeds = [b"retraction", &paper_child_seed(&paper)?[..]],
+        bump = retraction.bump
+    )]
+    pub retraction: Option<Account<'info, Retraction>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: May already be closed; its status is checked in the handler while it exists
+    pub paper: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote", &paper_child_seed(&paper)?[..], voter.key().as_ref()],
+        bump = vote.bump
+    )]
+    pub vote: Account<'info, Vote>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePaper<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        close = author,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        close = author,
+        seeds = [b"paper-metadata", paper.key().as_ref()],
+        bump = metadata.bump
+    )]
+    pub metadata: Account<'info, PaperMetadata>,
+
+    /// Required when the paper is retracted with funding outstanding
+    #[account(
+        seeds = [b"retraction", &paper.child_seed(&paper.key())[..]],
+        bump = retraction.bump
+    )]
+    pub retraction: Option<Account<'info, Retraction>>,
+}
+
+#[derive(Accounts)]
+pub struct RetractPaper<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"paper", ResearchPaper::seed_author(&paper), paper.nonce.to_le_bytes().as_ref()],
+        bump = paper.bump
+    )]
+    pub paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Retraction::SPACE,
+        seeds = [b"retraction", &paper.child_seed(&paper.key())[..]],
+        bump
+    )]
+    pub retraction: Account<'info, Retraction>,
+
+    #[account(
+        seeds = [b"editor", authority.key().as_ref()],
+        bump = editor.bump
+    )]
+    pub editor: Option<Account<'info, Editor>>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(editor: Pubkey)]
+pub struct AddEditor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Editor::SPACE,
+        seeds = [b"editor", editor.as_ref()],
+        bump
+    )]
+    pub editor_account: Account<'info, Editor>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(editor: Pubkey)]
+pub struct RemoveEditor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"editor", editor.as_ref()],
+        bump = editor_account.bump
+    )]
+    pub editor_account: Account<'info, Editor>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct TogglePause<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct ClearPauseFlags<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct QueueSettingsChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PendingSettingsChange::SPACE,
+        seeds = [b"settings-change"],
+        bump
+    )]
+    pub settings_change: Account<'info, PendingSettingsChange>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSettingsChange<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"settings-change"],
+        bump = settings_change.bump
+    )]
+    pub settings_change: Account<'info, PendingSettingsChange>,
+
+    /// CHECK: Receives the rent of the queued change; must be the admin who queued it
+    #[account(mut, address = settings_change.proposed_by)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSettingsChange<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"settings-change"],
+        bump = settings_change.bump
+    )]
+    pub settings_change: Account<'info, PendingSettingsChange>,
+
+    /// CHECK: Receives the rent of the queued change; must be the admin who queued it
+    #[account(mut, address = settings_change.proposed_by)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProgramState<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// CHECK: Deserialized manually after reallocation since older layouts don't decode as `ProgramState`
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"program-state"],
+        bump
+    )]
+    pub program_state: UncheckedAccount<'info>,
+
+    /// Required to migrate from before version 3 once governance has been initialized
+    pub governance_mint: Option<Account<'info, Mint>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateResearchPaper<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Deserialized manually after reallocation since older layouts don't decode as `ResearchPaper`
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub paper: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PaperMetadata::SPACE,
+        seeds = [b"paper-metadata", paper.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, PaperMetadata>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Deserialized manually after reallocation since the old layout doesn't decode as `Vote`
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub vote: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub governance_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"governance-vault"],
+        bump,
+        token::mint = governance_mint,
+        token::authority = governance_vault,
+    )]
+    pub governance_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        token::mint = program_state.governance_mint,
+        token::authority = proposer,
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + GovernanceProposal::SPACE,
+        seeds = [b"proposal", program_state.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CastGovernanceVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        token::mint = program_state.governance_mint,
+        token::authority = voter,
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"governance-vault"],
+        bump,
+    )]
+    pub governance_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + GovernanceVote::SPACE,
+        seeds = [b"governance-vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub governance_vote: Account<'info, GovernanceVote>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct WithdrawGovernanceVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"governance-vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = governance_vote.bump
+    )]
+    pub governance_vote: Account<'info, GovernanceVote>,
+
+    #[account(
+        mut,
+        token::mint = program_state.governance_mint,
+        token::authority = voter,
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"governance-vault"],
+        bump,
+    )]
+    pub governance_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
+
+// Data structures
+#[account]
+pub struct ProgramState {
+    pub admin: Pubkey,
+    pub paper_count: u64, // Papers seeded by the old global counter; new papers use AuthorIndex
+    pub total_funding: u64,
+    pub platform_fee_rate: u16, // In basis points (100 = 1%)
+    pub min_funding_goal: u64,
+    pub max_funding_period: i64,
+    pub pause_flags: u8, // Bitmask of ProgramState::PAUSE_* families
+    pub bump: u8,
+    pub pending_admin: Option<Pubkey>, // Set by propose_admin, cleared on accept/cancel
+    pub settings_delay: i64, // Seconds a queued settings change waits before it can execute
+    pub vote_weight_divisor: u64, // Whole tokens per unit of vote weight
+    pub vote_weight_cap: u64,
+    pub governance_mint: Pubkey, // Default until initialize_governance runs
+    pub governance_quorum: u64, // Minimum escrowed tokens across for/against votes
+    pub governance_threshold_bps: u16, // Share of votes that must be exceeded in favour
+    pub governance_voting_period: i64,
+    pub proposal_count: u64,
+    pub version: u8,
+    // New fields go between `version` and `reserved`, shrinking `reserved` so the
+    // account size stays fixed; `migrate` fills their defaults for older versions.
+    pub voting_mode: VotingMode,
+    pub vote_credits_per_epoch: u64, // Most credits a voter may spend per epoch in quadratic mode
+    pub vote_epoch_duration: i64,
+    pub governance_decimals: u8, // Decimals of governance_mint, recorded by initialize_governance
+    pub vote_weight_zero_balance: u64, // Weight of a vote backed by no voting power
+    pub vote_weight_curve: WeightCurve,
+    pub voting_window_duration: i64, // Seconds a paper accepts votes after publication
+    pub reserved: [u8; ProgramState::RESERVED_BYTES],
+}
+
+impl ProgramState {
+    pub const SPACE: usize = 32 + 8 + 8 + 2 + 8 + 8 + 1 + 1 + (1 + 32) + 8 + 8 + 8 + 32 + 8 + 2 + 8 + 8
+        + 1 + 1 + 8 + 8 + 1 + 8 + 1 + 8 + Self::RESERVED_BYTES;
+    pub const CURRENT_VERSION: u8 = 4;
+    pub const RESERVED_BYTES: usize = 29;
+    pub const DEFAULT_SETTINGS_DELAY: i64 = 2 * 24 * 60 * 60; // 2 days
+    pub const MAX_SETTINGS_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days
+    pub const DEFAULT_VOTE_WEIGHT_DIVISOR: u64 = 1; // 1 token per unit of weight
+    pub const DEFAULT_VOTE_WEIGHT_CAP: u64 = 10; // Max 10x weight
+    pub const DEFAULT_VOTE_WEIGHT_ZERO_BALANCE: u64 = 1;
+    pub const DEFAULT_GOVERNANCE_THRESHOLD_BPS: u16 = 5000; // Simple majority
+    pub const DEFAULT_GOVERNANCE_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60; // 3 days
+    pub const DEFAULT_VOTE_CREDITS_PER_EPOCH: u64 = 100; // Up to 10x weight on a single paper
+    pub const DEFAULT_VOTE_EPOCH_DURATION: i64 = 7 * 24 * 60 * 60; // 1 week
+    pub const DEFAULT_VOTING_WINDOW_DURATION: i64 = 30 * 24 * 60 * 60; // 30 days
+
+    pub const PAUSE_SUBMISSIONS: u8 = 1 << 0;
+    pub const PAUSE_FUNDING: u8 = 1 << 1;
+    pub const PAUSE_VOTING: u8 = 1 << 2;
+    pub const PAUSE_CLAIMS: u8 = 1 << 3;
+    pub const PAUSE_REVIEWS: u8 = 1 << 4;
+    pub const PAUSE_REFUNDS: u8 = 1 << 5;
+    pub const PAUSE_ALL: u8 = Self::PAUSE_SUBMISSIONS
+        | Self::PAUSE_FUNDING
+        | Self::PAUSE_VOTING
+        | Self::PAUSE_CLAIMS
+        | Self::PAUSE_REVIEWS
+        | Self::PAUSE_REFUNDS;
+    /// Families halted by `toggle_pause`; claims and refunds stay open in an emergency
+    pub const PAUSE_EMERGENCY: u8 = Self::PAUSE_ALL & !(Self::PAUSE_CLAIMS | Self::PAUSE_REFUNDS);
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.pause_flags & flag != 0
+    }
+
+    /// Paper vote weight for `power` base units of voting power: whole tokens per divisor,
+    /// shaped by the curve and capped. Any escrowed power weighs at least as much as none, so
+    /// a lock smaller than one divisor still gets the zero-balance weight.
+    pub fn vote_weight(&self, power: u64) -> u64 {
+        std::cmp::max(self.escrowed_weight(power), self.vote_weight_zero_balance)
+    }
+
+    /// Quadratic credits a voter with escrowed `power` may spend per epoch: enough to buy the
+    /// weight their lock earns on one paper, up to `vote_credits_per_epoch`. The zero-balance
+    /// floor doesn't count, so fresh wallets and dust locks add no quadratic voting power.
+    pub fn vote_credits(&self, power: u64) -> u64 {
+        let weight = self.escrowed_weight(power);
+        std::cmp::min(weight.saturating_mul(weight), self.vote_credits_per_epoch)
+    }
+
+    /// Vote weight earned by `power` alone, before the zero-balance floor
+    fn escrowed_weight(&self, power: u64) -> u64 {
+        let units = 10u128
+            .checked_pow(self.governance_decimals as u32)
+            .map_or(0, |one_token| power as u128 / one_token / self.vote_weight_divisor as u128);
+        std::cmp::min(self.vote_weight_curve.apply(units), self.vote_weight_cap as u128) as u64
+    }
+
+    pub fn vote_weight_formula(&self) -> VoteWeightFormula {
+        VoteWeightFormula {
+            divisor: self.vote_weight_divisor,
+            cap: self.vote_weight_cap,
+            zero_balance_weight: self.vote_weight_zero_balance,
+            curve: self.vote_weight_curve,
+        }
+    }
+
+    pub fn set_vote_weight_formula(&mut self, formula: VoteWeightFormula) {
+        self.vote_weight_divisor = formula.divisor;
+        self.vote_weight_cap = formula.cap;
+        self.vote_weight_zero_balance = formula.zero_balance_weight;
+        self.vote_weight_curve = formula.curve;
+    }
+
+    /// Quadratic-voting credit epoch containing `timestamp`
+    pub fn vote_epoch(&self, timestamp: i64) -> u64 {
+        (timestamp / self.vote_epoch_duration) as u64
+    }
+
+    /// Fill defaults for fields that were zero-initialized when upgrading from an older version
+    pub fn migrate(&mut self) {
+        if self.version < 1 {
+            // The legacy `is_paused` flag decodes as a non-zero byte
+            if self.pause_flags != 0 {
+                self.pause_flags = Self::PAUSE_EMERGENCY;
+            }
+            self.settings_delay = Self::DEFAULT_SETTINGS_DELAY;
+            self.vote_weight_divisor = Self::DEFAULT_VOTE_WEIGHT_DIVISOR;
+            self.vote_weight_cap = Self::DEFAULT_VOTE_WEIGHT_CAP;
+            self.governance_threshold_bps = Self::DEFAULT_GOVERNANCE_THRESHOLD_BPS;
+            self.governance_voting_period = Self::DEFAULT_GOVERNANCE_VOTING_PERIOD;
+        }
+        if self.version < 2 {
+            self.voting_mode = VotingMode::Linear;
+            self.vote_credits_per_epoch = Self::DEFAULT_VOTE_CREDITS_PER_EPOCH;
+            self.vote_epoch_duration = Self::DEFAULT_VOTE_EPOCH_DURATION;
+        }
+        if self.version < 3 {
+            // The divisor was counted in base units; without a mint there are no decimals to
+            // convert with, so fall back to the default
+            self.vote_weight_divisor = if self.governance_mint == Pubkey::default() {
+                Self::DEFAULT_VOTE_WEIGHT_DIVISOR
+            } else {
+                10u64
+                    .checked_pow(self.governance_decimals as u32)
+                    .map_or(1, |one_token| std::cmp::max(self.vote_weight_divisor / one_token, 1))
+            };
+            self.vote_weight_zero_balance = Self::DEFAULT_VOTE_WEIGHT_ZERO_BALANCE;
+            self.vote_weight_curve = WeightCurve::Linear;
+        }
+        if self.version < 4 {
+            self.voting_window_duration = Self::DEFAULT_VOTING_WINDOW_DURATION;
+        }
+        self.version = Self::CURRENT_VERSION;
+    }
+}
+
+#[account]
+pub struct PendingSettingsChange {
+    pub platform_fee_rate: Option<u16>,
+    pub min_funding_goal: Option<u64>,
+    pub settings_delay: Option<i64>,
+    pub vote_weight: Option<VoteWeightFormula>,
+    pub proposed_by: Pubkey,
+    pub queued_at: i64,
+    pub effective_at: i64,
+    pub bump: u8,
+}
+
+impl PendingSettingsChange {
+    pub const SPACE: usize = (1 + 2) + (1 + 8) + (1 + 8) + (1 + VoteWeightFormula::SPACE) + 32 + 8 + 8 + 1;
+}
+
+/// Fixed-size paper state touched by funding, voting and status changes. The variable-length
+/// strings live in `PaperMetadata` so the hot paths never decode them.
+#[account]
+pub struct ResearchPaper {
+    pub id: u64,
+    pub author: Pubkey,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub funding_goal: u64,
+    pub funding_current: u64,
+    pub funding_deadline: i64,
+    pub upvotes: u64,
+    pub downvotes: u64,
+    pub status: PaperStatus,
+    pub review_score: u32,
+    pub review_count: u32,
+    pub bump: u8,
+    pub version: u8,
+    // New fields go between `version` and `reserved`; see `ProgramState`
+    pub manuscript_version: u32, // Latest PaperRevision version; 1 is the original submission
+    pub citations_out: u32, // Citation accounts created by this paper, internal and external
+    pub citations_in: u32, // Citation accounts from other papers on the platform
+    pub category: ResearchCategory,
+    pub nonce: u64, // Author-local index from AuthorIndex; seeds the paper address with `author`
+    pub content_id: ContentId, // Manuscript CID of the original submission
+    pub voting_opens_at: i64, // Zero until the paper is published
+    pub voting_closes_at: i64,
+    pub legacy_seeds: bool, // Created before per-author nonces; see `seed_author` and `child_seed`
+    pub earliest_unlock_at: i64, // Earliest lock expiry behind a vote; zero if none
+    pub reserved: [u8; ResearchPaper::RESERVED_BYTES],
+}
+
+impl ResearchPaper {
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 4 + 4 + 1 + 1
+        + 4 + 4 + 4 + 1 + 8 + ContentId::SPACE + 8 + 8 + 1 + 8 + Self::RESERVED_BYTES;
+    pub const CURRENT_VERSION: u8 = 9;
+    pub const RESERVED_BYTES: usize = 23;
+
+    /// Informational platform-wide id for the author's `nonce`-th paper. Derived rather than
+    /// counted so submissions never write-lock shared state.
+    pub fn derive_id(author: &Pubkey, nonce: u64) -> u64 {
+        let digest = hash(&[author.as_ref(), nonce.to_le_bytes().as_ref()].concat()).to_bytes();
+        u64::from_le_bytes(digest[..8].try_into().unwrap())
+    }
+
+    /// Seed between `b"paper"` and the nonce in this paper's address. Papers created before
+    /// per-author nonces were seeded by their id alone, which migration copies into `nonce`;
+    /// an empty seed leaves that address unchanged.
+    pub fn seed_author(&self) -> &[u8] {
+        if self.legacy_seeds {
+            &[]
+        } else {
+            self.author.as_ref()
+        }
+    }
+
+    /// Seed of the escrow, funding, vote and other accounts keyed by this paper: its address,
+    /// or its id for papers created before per-author nonces
+    pub fn child_seed(&self, address: &Pubkey) -> Vec<u8> {
+        if self.legacy_seeds {
+            self.id.to_le_bytes().to_vec()
+        } else {
+            address.to_bytes().to_vec()
+        }
+    }
+
+    /// Note the expiry of a lock backing a vote, which the voting window must not outlast
+    pub fn record_lock(&mut self, unlock_at: i64) {
+        if self.earliest_unlock_at == 0 || unlock_at < self.earliest_unlock_at {
+            self.earliest_unlock_at = unlock_at;
+        }
+    }
+
+    /// Add a vote's weight to the tally for its direction
+    pub fn add_vote(&mut self, is_upvote: bool, weight: u64) {
+        if is_upvote {
+            self.upvotes = self.upvotes.checked_add(weight).unwrap();
+        } else {
+            self.downvotes = self.downvotes.checked_add(weight).unwrap();
+        }
+    }
+
+    /// Remove a previously added vote's weight from the tally for its direction
+    pub fn remove_vote(&mut self, is_upvote: bool, weight: u64) {
+        if is_upvote {
+            self.upvotes = self.upvotes.checked_sub(weight).unwrap();
+        } else {
+            self.downvotes = self.downvotes.checked_sub(weight).unwrap();
+        }
+    }
+
+    /// Accept votes from `now` for the platform's default window
+    pub fn open_voting(&mut self, program_state: &ProgramState, now: i64) {
+        self.voting_opens_at = now;
+        self.voting_closes_at = now + program_state.voting_window_duration;
+    }
+
+    pub fn require_voting_open(&self, now: i64) -> Result<()> {
+        require!(now >= self.voting_opens_at, ResearchError::VotingNotOpen);
+        require!(now < self.voting_closes_at, ResearchError::VotingClosed);
+        Ok(())
+    }
+
+    /// Move to `next` if the transition table allows it. Every status change goes through here.
+    pub fn transition(&mut self, next: PaperStatus, timestamp: i64) -> Result<()> {
+        require!(self.status.can_transition_to(next), ResearchError::InvalidTransition);
+        let previous = self.status;
+        self.status = next;
+        self.updated_at = timestamp;
+
+        emit!(PaperStatusChangedEvent {
+            paper_id: self.id,
+            previous,
+            status: next,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+/// Descriptive paper fields, read by clients and written only while the paper is a draft
+#[account]
+pub struct PaperMetadata {
+    pub paper: Pubkey,
+    pub bump: u8,
+    pub title: String,
+    pub abstract_text: String,
+    pub authors: Vec<String>,
+    pub keywords: Vec<String>,
+}
+
+impl PaperMetadata {
+    /// Size with every string and vector empty
+    pub const SPACE: usize = 32 + 1 + 4 + 4 + 4 + 4;
+    pub const MAX_KEYWORDS: usize = 5;
+    pub const MAX_KEYWORD_LENGTH: usize = 32;
+
+    /// Exact serialized size of metadata with the given fields, excluding the discriminator
+    pub fn space_for(
+        title: &str,
+        abstract_text: &str,
+        authors: &[String],
+        keywords: &[String],
+    ) -> usize {
+        Self::SPACE
+            + title.len()
+            + abstract_text.len()
+            + authors.iter().map(|author| 4 + author.len()).sum::<usize>()
+            + keywords.iter().map(|keyword| 4 + keyword.len()).sum::<usize>()
+    }
+
+    /// Exact serialized size of this metadata, excluding the discriminator
+    pub fn space(&self) -> usize {
+        Self::space_for(
+            &self.title,
+            &self.abstract_text,
+            &self.authors,
+            &self.keywords,
+        )
+    }
+
+    pub fn validate_title(title: &str) -> Result<()> {
+        require!(!title.is_empty() && title.len() <= 100, ResearchError::InvalidTitle);
+        Ok(())
+    }
+
+    pub fn validate_abstract(abstract_text: &str) -> Result<()> {
+        require!(!abstract_text.is_empty() && abstract_text.len() <= 1000, ResearchError::InvalidAbstract);
+        Ok(())
+    }
+
+    pub fn validate_authors(authors: &[String]) -> Result<()> {
+        require!(!authors.is_empty() && authors.len() <= 10, ResearchError::InvalidAuthors);
+        Ok(())
+    }
+
+    pub fn validate_keywords(keywords: &[String]) -> Result<()> {
+        require!(
+            keywords.len() <= Self::MAX_KEYWORDS
+                && keywords
+                    .iter()
+                    .all(|keyword| !keyword.is_empty() && keyword.len() <= Self::MAX_KEYWORD_LENGTH),
+            ResearchError::InvalidKeywords
+        );
+        Ok(())
+    }
+}
+
+/// Version 6 paper layout, which held the metadata strings inline. Papers created before
+/// version 7 share the `ResearchPaper` discriminator and decode only through this struct.
+/// Version 7 papers have the version 8 layout plus the `is_published` flag dropped in version 8.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyResearchPaper {
+    pub id: u64,
+    pub author: Pubkey,
+    pub title: String,
+    pub abstract_text: String,
+    pub ipfs_hash: String, // Cleared when migrating to version 6; see `content_id`
+    pub authors: Vec<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub is_published: bool,
+    pub funding_goal: u64,
+    pub funding_current: u64,
+    pub funding_deadline: i64,
+    pub upvotes: u64,
+    pub downvotes: u64,
+    pub status: PaperStatus,
+    pub review_score: u32,
+    pub review_count: u32,
+    pub bump: u8,
+    pub version: u8,
+    pub manuscript_version: u32,
+    pub citations_out: u32,
+    pub citations_in: u32,
+    pub category: ResearchCategory,
+    pub keywords: Vec<String>,
+    pub nonce: u64,
+    pub content_id: ContentId,
+    pub reserved: [u8; LegacyResearchPaper::RESERVED_BYTES],
+}
+
+impl LegacyResearchPaper {
+    pub const RESERVED_BYTES: usize = 16;
+    /// Bytes a version 0 paper grows by so it decodes with the version 6 layout
+    pub const MIGRATION_TAIL: usize =
+        1 + 4 + 4 + 4 + 1 + 4 + 8 + ContentId::SPACE + Self::RESERVED_BYTES;
+    /// Account length of a version 8 paper, including the discriminator
+    pub const V8_LEN: usize = 8 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 4 + 4 + 1 + 1
+        + 4 + 4 + 4 + 1 + 8 + ContentId::SPACE + 16;
+    /// Account length of a version 7 paper, including the discriminator
+    pub const V7_LEN: usize = Self::V8_LEN + 1;
+    /// Offset of the version 7 `is_published` flag, including the discriminator
+    pub const V7_IS_PUBLISHED_OFFSET: usize = 8 + 8 + 32 + 8 + 8;
+
+    pub fn try_deserialize(data: &[u8]) -> Result<Self> {
+        require!(
+            data.starts_with(ResearchPaper::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        Self::deserialize(&mut &data[ResearchPaper::DISCRIMINATOR.len()..])
+            .map_err(|_| error!(anchor_lang::error::ErrorCode::AccountDidNotDeserialize))
+    }
+
+    /// Fill defaults for fields that were zero-initialized when upgrading from an older version.
+    /// A legacy hash that is not a CID leaves `content_id` unset for the author to fill in.
+    pub fn migrate(&mut self) {
+        if self.version < 2 {
+            self.manuscript_version = 1;
+        }
+        if self.version < 4 {
+            // Papers from before categories existed are unclassified and unindexed
+            self.category = ResearchCategory::Other;
+        }
+        if self.version < 5 {
+            // Keep the address and child accounts seeded by the old global id
+            self.nonce = self.id;
+        }
+        if self.version < 6 {
+            self.content_id = ContentId::parse(&self.ipfs_hash).unwrap_or(ContentId::UNSET);
+            self.ipfs_hash.clear();
+        }
+    }
+
+    /// The fixed-size part of this paper in the current layout
+    pub fn into_paper(self) -> ResearchPaper {
+        ResearchPaper {
+            id: self.id,
+            author: self.author,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            funding_goal: self.funding_goal,
+            funding_current: self.funding_current,
+            funding_deadline: self.funding_deadline,
+            upvotes: self.upvotes,
+            downvotes: self.downvotes,
+            status: self.status,
+            review_score: self.review_score,
+            review_count: self.review_count,
+            bump: self.bump,
+            version: ResearchPaper::CURRENT_VERSION,
+            manuscript_version: self.manuscript_version,
+            citations_out: self.citations_out,
+            citations_in: self.citations_in,
+            category: self.category,
+            nonce: self.nonce,
+            content_id: self.content_id,
+            voting_opens_at: 0,
+            voting_closes_at: 0,
+            legacy_seeds: self.version < 5,
+            earliest_unlock_at: 0,
+            reserved: [0; ResearchPaper::RESERVED_BYTES],
+        }
+    }
+}
+
+#[account]
+pub struct ManuscriptRegistry {
+    pub content_id: ContentId,
+    pub paper: Pubkey,
+    pub submitter: Pubkey,
+    pub submitted_at: i64, // First submission of this manuscript, for priority disputes
+    pub bump: u8,
+}
+
+impl ManuscriptRegistry {
+    pub const SPACE: usize = ContentId::SPACE + 32 + 32 + 8 + 1;
+
+    /// Claim a freshly created registry entry; an existing entry means the manuscript was
+    /// already submitted
+    pub fn register(
+        &mut self,
+        content_id: ContentId,
+        paper: Pubkey,
+        submitter: Pubkey,
+        timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(self.paper == Pubkey::default(), ResearchError::DuplicateManuscript);
+        self.content_id = content_id;
+        self.paper = paper;
+        self.submitter = submitter;
+        self.submitted_at = timestamp;
+        self.bump = bump;
+
+        emit!(ManuscriptRegisteredEvent {
+            paper,
+            submitter,
+            digest: content_id.digest,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct PriorityCommitment {
+    pub committer: Pubkey,
+    pub commitment: [u8; 32],
+    pub committed_at: i64,
+    pub paper: Option<Pubkey>, // Set once revealed
+    pub revealed_at: i64,
+    pub bump: u8,
+}
+
+impl PriorityCommitment {
+    pub const SPACE: usize = 32 + 32 + 8 + (1 + 32) + 8 + 1;
+}
+
+#[account]
+pub struct AuthorIndex {
+    pub author: Pubkey,
+    pub paper_count: u64, // Next paper nonce for this author
+    pub bump: u8,
+}
+
+impl AuthorIndex {
+    pub const SPACE: usize = 32 + 8 + 1;
+}
+
+#[account]
+pub struct CategoryIndex {
+    pub category: ResearchCategory,
+    pub paper_count: u64, // Papers indexed so far; the last page is paper_count / PAGE_SIZE
+    pub bump: u8,
+}
+
+impl CategoryIndex {
+    pub const SPACE: usize = 1 + 8 + 1;
+
+    pub fn current_page(&self) -> u32 {
+        (self.paper_count / CategoryIndexPage::PAGE_SIZE as u64) as u32
+    }
+}
+
+#[account]
+pub struct CategoryIndexPage {
+    pub category: ResearchCategory,
+    pub page: u32,
+    pub papers: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl CategoryIndexPage {
+    pub const PAGE_SIZE: usize = 64;
+    pub const SPACE: usize = 1 + 4 + (4 + Self::PAGE_SIZE * 32) + 1;
+}
+
+#[account]
+pub struct PaperRevision {
+    pub paper_id: u64,
+    pub version: u32,
+    pub content_id: ContentId,
+    pub content_hash: [u8; 32], // Hash of the manuscript file itself
+    pub changelog_cid: ContentId,
+    pub author: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl PaperRevision {
+    pub const SPACE: usize = 8 + 4 + ContentId::SPACE + 32 + ContentId::SPACE + 32 + 8 + 1;
+}
+
+#[account]
+pub struct Citation {
+    pub citing_paper_id: u64,
+    pub target: CitationTarget,
+    pub created_by: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl Citation {
+    pub const SPACE: usize = 8 + (1 + 8) + 32 + 8 + 1; // DOI citations add the DOI's length
+    pub const MAX_DOI_LENGTH: usize = 128;
+
+    /// DOIs look like `10.<registrant>/<suffix>`
+    pub fn validate_doi(doi: &str) -> Result<()> {
+        require!(
+            doi.len() <= Self::MAX_DOI_LENGTH
+                && doi.starts_with("10.")
+                && doi.find('/').is_some_and(|slash| slash > 3 && slash < doi.len() - 1),
+            ResearchError::InvalidCitation
+        );
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Retraction {
+    pub paper_id: u64,
+    pub retracted_by: Pubkey,
+    pub reason: RetractionReason,
+    pub notice_cid: ContentId,
+    pub previous_status: PaperStatus,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl Retraction {
+    pub const SPACE: usize = 8 + 32 + 1 + ContentId::SPACE + 1 + 8 + 1;
+}
+
+#[account]
+pub struct ResearcherProfile {
+    pub wallet: Pubkey,
+    pub display_name: String,
+    pub affiliation: String,
+    pub orcid: String,
+    pub bio_cid: Option<ContentId>,
+    pub papers_submitted: u32,
+    pub papers_published: u32,
+    pub papers_completed: u32,
+    pub total_funding_raised: u64,
+    pub reviews_done: u32,
+    pub reputation: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ResearcherProfile {
+    pub const SPACE: usize = 32
+        + 4 + Self::MAX_DISPLAY_NAME_LENGTH
+        + 4 + Self::MAX_AFFILIATION_LENGTH
+        + 4 + Self::ORCID_LENGTH
+        + 1 + ContentId::SPACE
+        + 4 + 4 + 4 + 8 + 4 + 8 + 8 + 8 + 1;
+    pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+    pub const MAX_AFFILIATION_LENGTH: usize = 128;
+    pub const ORCID_LENGTH: usize = 19;
+    pub const REPUTATION_PER_PUBLICATION: u64 = 10;
+    pub const REPUTATION_PER_COMPLETION: u64 = 25;
+
+    /// Record the owner of a profile just created by `init_if_needed`
+    pub fn initialize_if_new(&mut self, wallet: Pubkey, timestamp: i64, bump: u8) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.created_at = timestamp;
+            self.bump = bump;
+        }
+    }
+
+    pub fn validate_display_name(display_name: &str) -> Result<()> {
+        require!(
+            !display_name.is_empty() && display_name.len() <= Self::MAX_DISPLAY_NAME_LENGTH,
+            ResearchError::InvalidDisplayName
+        );
+        Ok(())
+    }
+
+    pub fn validate_affiliation(affiliation: &str) -> Result<()> {
+        require!(affiliation.len() <= Self::MAX_AFFILIATION_LENGTH, ResearchError::InvalidAffiliation);
+        Ok(())
+    }
+
+    /// Empty, or an ORCID iD such as `0000-0002-1825-0097` with a valid ISO 7064 11,2 check digit
+    pub fn validate_orcid(orcid: &str) -> Result<()> {
+        if orcid.is_empty() {
+            return Ok(());
+        }
+        require!(orcid.len() == Self::ORCID_LENGTH, ResearchError::InvalidOrcid);
+
+        let bytes = orcid.as_bytes();
+        let mut total: u32 = 0;
+        let mut digits = 0;
+        for (i, &byte) in bytes[..Self::ORCID_LENGTH - 1].iter().enumerate() {
+            if i % 5 == 4 {
+                require!(byte == b'-', ResearchError::InvalidOrcid);
+                continue;
+            }
+            require!(byte.is_ascii_digit(), ResearchError::InvalidOrcid);
+            total = (total + (byte - b'0') as u32) * 2;
+            digits += 1;
+        }
+        require!(digits == 15, ResearchError::InvalidOrcid);
+
+        let check = (12 - total % 11) % 11;
+        let expected = if check == 10 { b'X' } else { b'0' + check as u8 };
+        require!(bytes[Self::ORCID_LENGTH - 1] == expected, ResearchError::InvalidOrcid);
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Editor {
+    pub editor: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl Editor {
+    pub const SPACE: usize = 32 + 32 + 8 + 1;
+}
+
+#[account]
+pub struct Funding {
+    pub paper_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
 
-        if let Some(fee_rate) = platform_fee_rate {
-            require!(fee_rate <= 1000, ResearchError::FeeTooHigh); // Max 10%
-            program_state.platform_fee_rate = fee_rate;
+impl Funding {
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+}
+
+#[account]
+pub struct Vote {
+    pub paper_id: u64,
+    pub voter: Pubkey,
+    pub is_upvote: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+    pub credits: u64, // Quadratic-mode credits spent on this vote; zero for linear votes
+}
+
+impl Vote {
+    pub const SPACE: usize = 8 + 32 + 1 + 8 + 8 + 1 + 8;
+    /// Size of votes cast before `credits` was added; see `migrate_vote`
+    pub const LEGACY_SPACE: usize = 8 + 32 + 1 + 8 + 8 + 1;
+}
+
+/// Quadratic-voting credits a voter has spent in one epoch
+#[account]
+pub struct VoterCredits {
+    pub voter: Pubkey,
+    pub epoch: u64,
+    pub spent: u64,
+    pub bump: u8,
+}
+
+impl VoterCredits {
+    pub const SPACE: usize = 32 + 8 + 8 + 1;
+}
+
+/// A paper's vote tally frozen when its voting window closed
+#[account]
+pub struct VoteTally {
+    pub paper: Pubkey,
+    pub paper_id: u64,
+    pub upvotes: u64,
+    pub downvotes: u64,
+    pub voting_opens_at: i64,
+    pub voting_closes_at: i64,
+    pub finalized_at: i64,
+    pub bump: u8,
+}
+
+impl VoteTally {
+    pub const SPACE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+/// BIOX locked in the governance vault; voting power decays linearly to zero at `unlock_at`
+#[account]
+pub struct VoteEscrow {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub locked_at: i64, // Last time the lock was created, topped up or extended
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl VoteEscrow {
+    pub const SPACE: usize = 32 + 8 + 8 + 8 + 1;
+    pub const MAX_LOCK_DURATION: i64 = 4 * 365 * 24 * 60 * 60; // 4 years
+
+    /// Power at `now`: the full locked amount for a maximum-length lock, scaled down by the
+    /// share of the maximum duration still remaining
+    pub fn voting_power(&self, now: i64) -> u64 {
+        let remaining = (self.unlock_at - now).clamp(0, Self::MAX_LOCK_DURATION);
+        (self.amount as u128 * remaining as u128 / Self::MAX_LOCK_DURATION as u128) as u64
+    }
+}
+
+#[account]
+pub struct GovernanceProposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub change: ParameterChange,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl GovernanceProposal {
+    pub const SPACE: usize = 8 + 32 + ParameterChange::SPACE + 8 + 8 + 8 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct GovernanceVote {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl GovernanceVote {
+    pub const SPACE: usize = 8 + 32 + 1 + 8 + 8 + 1;
+}
+
+/// A single platform parameter that a governance proposal can change
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterChange {
+    PlatformFeeRate(u16),
+    MinFundingGoal(u64),
+    MaxFundingPeriod(i64),
+    VoteWeightDivisor(u64),
+    VoteWeightCap(u64),
+    GovernanceQuorum(u64),
+    GovernanceThreshold(u16),
+    GovernanceVotingPeriod(i64),
+    VotingMode(VotingMode),
+    VoteCreditsPerEpoch(u64),
+    VoteEpochDuration(i64),
+    VoteWeightZeroBalance(u64),
+    VoteWeightCurve(WeightCurve),
+    VotingWindowDuration(i64),
+}
+
+impl ParameterChange {
+    pub const SPACE: usize = 1 + 8;
+
+    /// Check the new value on its own and against the parameters it must stay consistent with
+    pub fn validate(&self, program_state: &ProgramState) -> Result<()> {
+        match *self {
+            ParameterChange::PlatformFeeRate(fee_rate) => {
+                require!(fee_rate <= 1000, ResearchError::FeeTooHigh); // Max 10%
+            }
+            ParameterChange::MinFundingGoal(min_goal) => {
+                require!(min_goal > 0, ResearchError::InvalidParameterValue);
+            }
+            ParameterChange::MaxFundingPeriod(period) => {
+                require!(
+                    (24 * 60 * 60..=365 * 24 * 60 * 60).contains(&period),
+                    ResearchError::InvalidParameterValue
+                );
+            }
+            ParameterChange::VoteWeightDivisor(divisor) => {
+                require!(divisor > 0, ResearchError::InvalidParameterValue);
+            }
+            ParameterChange::VoteWeightCap(cap) => {
+                require!(
+                    cap > 0 && cap >= program_state.vote_weight_zero_balance,
+                    ResearchError::InvalidParameterValue
+                );
+            }
+            ParameterChange::GovernanceQuorum(quorum) => {
+                require!(quorum > 0, ResearchError::InvalidParameterValue);
+            }
+            ParameterChange::GovernanceThreshold(threshold_bps) => {
+                // Proposals must beat the threshold, so 100% could never pass
+                require!(
+                    threshold_bps > 0 && threshold_bps < 10000,
+                    ResearchError::InvalidParameterValue
+                );
+            }
+            ParameterChange::GovernanceVotingPeriod(period) => {
+                require!(
+                    period > 0 && period <= 30 * 24 * 60 * 60,
+                    ResearchError::InvalidParameterValue
+                );
+            }
+            ParameterChange::VotingMode(_) => {}
+            ParameterChange::VoteCreditsPerEpoch(credits) => {
+                require!(credits > 0, ResearchError::InvalidParameterValue);
+            }
+            ParameterChange::VoteEpochDuration(duration) => {
+                require!(
+                    (60 * 60..=365 * 24 * 60 * 60).contains(&duration),
+                    ResearchError::InvalidParameterValue
+                );
+            }
+            ParameterChange::VoteWeightZeroBalance(weight) => {
+                require!(weight <= program_state.vote_weight_cap, ResearchError::InvalidParameterValue);
+            }
+            ParameterChange::VoteWeightCurve(_) => {}
+            ParameterChange::VotingWindowDuration(duration) => {
+                require!(
+                    (60 * 60..=365 * 24 * 60 * 60).contains(&duration),
+                    ResearchError::InvalidParameterValue
+                );
+            }
         }
+        Ok(())
+    }
 
-        if let Some(min_goal) = min_funding_goal {
-            program_state.min_funding_goal = min_goal;
+    pub fn apply(&self, program_state: &mut ProgramState) {
+        match *self {
+            ParameterChange::PlatformFeeRate(fee_rate) => program_state.platform_fee_rate = fee_rate,
+            ParameterChange::MinFundingGoal(min_goal) => program_state.min_funding_goal = min_goal,
+            ParameterChange::MaxFundingPeriod(period) => program_state.max_funding_period = period,
+            ParameterChange::VoteWeightDivisor(divisor) => program_state.vote_weight_divisor = divisor,
+            ParameterChange::VoteWeightCap(cap) => program_state.vote_weight_cap = cap,
+            ParameterChange::GovernanceQuorum(quorum) => program_state.governance_quorum = quorum,
+            ParameterChange::GovernanceThreshold(threshold_bps) => {
+                program_state.governance_threshold_bps = threshold_bps
+            }
+            ParameterChange::GovernanceVotingPeriod(period) => {
+                program_state.governance_voting_period = period
+            }
+            ParameterChange::VotingMode(mode) => program_state.voting_mode = mode,
+            ParameterChange::VoteCreditsPerEpoch(credits) => {
+                program_state.vote_credits_per_epoch = credits
+            }
+            ParameterChange::VoteEpochDuration(duration) => {
+                program_state.vote_epoch_duration = duration
+            }
+            ParameterChange::VoteWeightZeroBalance(weight) => {
+                program_state.vote_weight_zero_balance = weight
+            }
+            ParameterChange::VoteWeightCurve(curve) => program_state.vote_weight_curve = curve,
+            ParameterChange::VotingWindowDuration(duration) => {
+                program_state.voting_window_duration = duration
+            }
         }
+    }
+}
+
+/// The admin-editable inputs to `ProgramState::vote_weight`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct VoteWeightFormula {
+    pub divisor: u64, // Whole tokens per unit of weight
+    pub cap: u64,
+    pub zero_balance_weight: u64,
+    pub curve: WeightCurve,
+}
+
+impl VoteWeightFormula {
+    pub const SPACE: usize = 8 + 8 + 8 + 1;
 
+    pub fn validate(&self) -> Result<()> {
+        // No vote weighs more than the cap, including votes backed by nothing
+        require!(
+            self.divisor > 0 && self.cap > 0 && self.zero_balance_weight <= self.cap,
+            ResearchError::InvalidParameterValue
+        );
         Ok(())
     }
 }
 
-// Account validation structs
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = admin,
-        space = 8 + ProgramState::SPACE,
-        seeds = [b"program-state"],
-        bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
-    
-    pub system_program: Program<'info, System>,
+/// Shape of vote weight as voting power grows
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WeightCurve {
+    Linear,
+    SquareRoot,
+    Logarithmic, // One unit of weight per doubling, starting at 1 for the first unit
 }
 
-#[derive(Accounts)]
-#[instruction(title: String, abstract_text: String, ipfs_hash: String, authors: Vec<String>)]
-pub struct SubmitPaper<'info> {
-    #[account(mut)]
-    pub author: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = author,
-        space = 8 + ResearchPaper::SPACE + title.len() + abstract_text.len() + ipfs_hash.len() + (authors.len() * 50),
-        seeds = [b"paper", program_state.paper_count.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub paper: Account<'info, ResearchPaper>,
+impl WeightCurve {
+    pub fn apply(self, units: u128) -> u128 {
+        match self {
+            WeightCurve::Linear => units,
+            WeightCurve::SquareRoot => units.isqrt(),
+            WeightCurve::Logarithmic => units.checked_ilog2().map_or(0, |log| log as u128 + 1),
+        }
+    }
+}
 
-    #[account(
-        mut,
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
-    
-    pub system_program: Program<'info, System>,
+/// How paper vote weight is computed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VotingMode {
+    Linear,    // Weight from vote-escrowed BIOX
+    Quadratic, // Weight is the square root of credits spent
 }
 
-#[derive(Accounts)]
-#[instruction(paper_id: u64)]
-pub struct PublishPaper<'info> {
-    pub authority: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"paper", paper_id.to_le_bytes().as_ref()],
-        bump = paper.bump
-    )]
-    pub paper: Account<'info, ResearchPaper>,
-    
-    #[account(
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PaperStatus {
+    Draft,
+    Published,
+    FullyFunded,
+    Completed,
+    Rejected,
+    Retracted,
+    UnderReview,
+    Expired,
+    Cancelled,
 }
 
-#[derive(Accounts)]
-#[instruction(paper_id: u64, amount: u64)]
-pub struct FundPaper<'info> {
-    #[account(mut)]
-    pub funder: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"paper", paper_id.to_le_bytes().as_ref()],
-        bump = paper.bump
-    )]
-    pub paper: Account<'info, ResearchPaper>,
-    
-    /// CHECK: Token account validation handled manually
-    #[account(mut)]
-    pub funder_token_account: AccountInfo<'info>,
-    
-    /// CHECK: Token account validation handled manually
-    #[account(
-        mut,
-        seeds = [b"paper-token", paper_id.to_le_bytes().as_ref()],
-        bump,
-    )]
-    pub paper_token_account: AccountInfo<'info>,
+impl PaperStatus {
+    /// The paper status transition table
+    pub fn can_transition_to(self, next: PaperStatus) -> bool {
+        use PaperStatus::*;
+        matches!(
+            (self, next),
+            (Draft, UnderReview | Published | Cancelled)
+                | (UnderReview, Published | Rejected | Cancelled)
+                | (Published, FullyFunded | Expired | Retracted)
+                | (FullyFunded, Completed | Retracted)
+                | (Completed, Retracted)
+        )
+    }
 
-    /// CHECK: Token account validation handled manually
-    #[account(
-        mut,
-        seeds = [b"platform-vault"],
-        bump,
-    )]
-    pub platform_token_account: AccountInfo<'info>,
-    
-    #[account(
-        init,
-        payer = funder,
-        space = 8 + Funding::SPACE,
-        seeds = [b"funding", paper_id.to_le_bytes().as_ref(), funder.key().as_ref()],
-        bump
-    )]
-    pub funding: Account<'info, Funding>,
+    /// Whether the paper has been published, including papers that later settled or were retracted
+    pub fn is_published(self) -> bool {
+        matches!(
+            self,
+            PaperStatus::Published
+                | PaperStatus::FullyFunded
+                | PaperStatus::Completed
+                | PaperStatus::Retracted
+                | PaperStatus::Expired
+        )
+    }
 
-    #[account(
-        mut,
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
-    
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+    /// Settled papers hold no funds and take no further actions
+    pub fn is_final(self) -> bool {
+        matches!(
+            self,
+            PaperStatus::Completed | PaperStatus::Rejected | PaperStatus::Cancelled
+        )
+    }
 }
 
-#[derive(Accounts)]
-#[instruction(paper_id: u64, is_upvote: bool)]
-pub struct VotePaper<'info> {
-    #[account(mut)]
-    pub voter: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"paper", paper_id.to_le_bytes().as_ref()],
-        bump = paper.bump
-    )]
-    pub paper: Account<'info, ResearchPaper>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResearchCategory {
+    Genomics,
+    Neuroscience,
+    Oncology,
+    Immunology,
+    Microbiology,
+    Bioinformatics,
+    Pharmacology,
+    Epidemiology,
+    SyntheticBiology,
+    Other,
+}
 
-    #[account(
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CitationTarget {
+    Paper(u64),
+    Doi(String),
+}
 
-    /// CHECK: Token account validation handled manually
-    pub voter_token_account: AccountInfo<'info>,
-    
-    #[account(
-        init,
-        payer = voter,
-        space = 8 + Vote::SPACE,
-        seeds = [b"vote", paper_id.to_le_bytes().as_ref(), voter.key().as_ref()],
-        bump
-    )]
-    pub vote: Account<'info, Vote>,
-    
-    pub system_program: Program<'info, System>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RetractionReason {
+    AuthorRequest,
+    Misconduct,
+    DataError,
+    Plagiarism,
+    EthicalConcerns,
+    Duplicate,
+    Other,
 }
 
-#[derive(Accounts)]
-#[instruction(paper_id: u64)]
-pub struct ClaimFunds<'info> {
-    pub author: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"paper", paper_id.to_le_bytes().as_ref()],
-        bump = paper.bump,
-        constraint = paper.author == author.key()
-    )]
-    pub paper: Account<'info, ResearchPaper>,
-    
-    /// CHECK: Token account validation handled manually
-    #[account(
-        mut,
-        seeds = [b"paper-token", paper_id.to_le_bytes().as_ref()],
-        bump,
-    )]
-    pub paper_token_account: AccountInfo<'info>,
-    
-    /// CHECK: Token account validation handled manually
-    #[account(mut)]
-    pub author_token_account: AccountInfo<'info>,
+// Events
+#[event]
+pub struct PaperSubmittedEvent {
+    pub paper_id: u64,
+    pub author: Pubkey,
+    pub title: String,
+    pub timestamp: i64,
+}
 
-    #[account(
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
-    
-    pub token_program: Program<'info, Token>,
+#[event]
+pub struct PaperUpdatedEvent {
+    pub paper_id: u64,
+    pub author: Pubkey,
+    pub title: String,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct TogglePause<'info> {
-    pub admin: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
+#[event]
+pub struct ManuscriptRegisteredEvent {
+    pub paper: Pubkey,
+    pub submitter: Pubkey,
+    pub digest: [u8; 32],
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct UpdateSettings<'info> {
-    pub admin: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"program-state"],
-        bump = program_state.bump
-    )]
-    pub program_state: Account<'info, ProgramState>,
+#[event]
+pub struct PriorityCommittedEvent {
+    pub committer: Pubkey,
+    pub commitment: [u8; 32],
+    pub timestamp: i64,
 }
 
-// Data structures
-#[account]
-pub struct ProgramState {
-    pub admin: Pubkey,
-    pub paper_count: u64,
-    pub total_funding: u64,
-    pub platform_fee_rate: u16, // In basis points (100 = 1%)
-    pub min_funding_goal: u64,
-    pub max_funding_period: i64,
-    pub is_paused: bool,
-    pub bump: u8,
+#[event]
+pub struct PriorityRevealedEvent {
+    pub committer: Pubkey,
+    pub paper: Pubkey,
+    pub commitment: [u8; 32],
+    pub committed_at: i64,
+    pub timestamp: i64,
 }
 
-impl ProgramState {
-    pub const SPACE: usize = 32 + 8 + 8 + 2 + 8 + 8 + 1 + 1;
+#[event]
+pub struct PaperStatusChangedEvent {
+    pub paper_id: u64,
+    pub previous: PaperStatus,
+    pub status: PaperStatus,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct ResearchPaper {
-    pub id: u64,
+#[event]
+pub struct PaperPublishedEvent {
+    pub paper_id: u64,
     pub author: Pubkey,
-    pub title: String,
-    pub abstract_text: String,
-    pub ipfs_hash: String,
-    pub authors: Vec<String>,
-    pub created_at: i64,
-    pub updated_at: i64,
-    pub is_published: bool,
-    pub funding_goal: u64,
-    pub funding_current: u64,
-    pub funding_deadline: i64,
+    pub voting_closes_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingWindowChangedEvent {
+    pub paper_id: u64,
+    pub opens_at: i64,
+    pub closes_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteTallyFinalizedEvent {
+    pub paper_id: u64,
     pub upvotes: u64,
     pub downvotes: u64,
-    pub status: PaperStatus,
-    pub review_score: u32,
-    pub review_count: u32,
-    pub bump: u8,
+    pub voting_opens_at: i64,
+    pub voting_closes_at: i64,
+    pub timestamp: i64,
 }
 
-impl ResearchPaper {
-    pub const SPACE: usize = 8 + 32 + 4 + 4 + 4 + 4 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 4 + 4 + 1;
+#[event]
+pub struct PaperRevisedEvent {
+    pub paper_id: u64,
+    pub version: u32,
+    pub content_id: ContentId,
+    pub content_hash: [u8; 32],
+    pub changelog_cid: ContentId,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct Funding {
+#[event]
+pub struct CitationAddedEvent {
+    pub citing_paper_id: u64,
+    pub target: CitationTarget,
+    pub citations_out: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperFundedEvent {
     pub paper_id: u64,
     pub funder: Pubkey,
     pub amount: u64,
     pub platform_fee: u64,
+    pub total_funding: u64,
     pub timestamp: i64,
-    pub bump: u8,
 }
 
-impl Funding {
-    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+#[event]
+pub struct PaperVotedEvent {
+    pub paper_id: u64,
+    pub voter: Pubkey,
+    pub is_upvote: bool,
+    pub weight: u64,
+    pub credits: u64,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct Vote {
+#[event]
+pub struct VoteChangedEvent {
     pub paper_id: u64,
     pub voter: Pubkey,
+    pub previous_is_upvote: bool,
+    pub previous_weight: u64,
     pub is_upvote: bool,
     pub weight: u64,
     pub timestamp: i64,
-    pub bump: u8,
 }
 
-impl Vote {
-    pub const SPACE: usize = 8 + 32 + 1 + 8 + 8 + 1;
+#[event]
+pub struct VoteRetractedEvent {
+    pub paper_id: u64,
+    pub voter: Pubkey,
+    pub is_upvote: bool,
+    pub weight: u64,
+    pub timestamp: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
-pub enum PaperStatus {
-    Draft,
-    Published,
-    FullyFunded,
-    Completed,
-    Rejected,
+#[event]
+pub struct TokensLockedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_locked: u64,
+    pub unlock_at: i64,
+    pub voting_power: u64,
+    pub timestamp: i64,
 }
 
-// Events
 #[event]
-pub struct PaperSubmittedEvent {
+pub struct LockWithdrawnEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsClaimedEvent {
     pub paper_id: u64,
     pub author: Pubkey,
-    pub title: String,
+    pub amount: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct PaperPublishedEvent {
+pub struct FundingRefundedEvent {
     pub paper_id: u64,
-    pub author: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub remaining_funding: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct PaperFundedEvent {
+pub struct FundingClosedEvent {
+    pub paper: Pubkey,
     pub paper_id: u64,
     pub funder: Pubkey,
     pub amount: u64,
     pub platform_fee: u64,
-    pub total_funding: u64,
+    pub funded_at: i64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct PaperVotedEvent {
+pub struct VoteClosedEvent {
+    pub paper: Pubkey,
     pub paper_id: u64,
     pub voter: Pubkey,
     pub is_upvote: bool,
     pub weight: u64,
+    pub voted_at: i64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct FundsClaimedEvent {
+pub struct PaperClosedEvent {
+    pub paper: Pubkey,
     pub paper_id: u64,
     pub author: Pubkey,
-    pub amount: u64,
+    pub status: PaperStatus,
+    pub content_id: ContentId,
+    pub manuscript_version: u32,
+    pub funding_goal: u64,
+    pub funding_current: u64,
+    pub upvotes: u64,
+    pub downvotes: u64,
+    pub citations_in: u32,
+    pub citations_out: u32,
+    pub created_at: i64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct PauseToggledEvent {
+pub struct PauseFlagChangedEvent {
+    pub flag: u8,
     pub is_paused: bool,
+    pub pause_flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettingsChangeQueuedEvent {
+    pub platform_fee_rate: Option<u16>,
+    pub min_funding_goal: Option<u64>,
+    pub settings_delay: Option<i64>,
+    pub vote_weight: Option<VoteWeightFormula>,
+    pub queued_at: i64,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct SettingsChangeCancelledEvent {
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettingsChangeExecutedEvent {
+    pub platform_fee_rate: u16,
+    pub min_funding_goal: u64,
+    pub settings_delay: i64,
+    pub vote_weight: VoteWeightFormula,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub change: ParameterChange,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceVoteCastEvent {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceVoteWithdrawnEvent {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+    pub change: ParameterChange,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperRetractedEvent {
+    pub paper_id: u64,
+    pub author: Pubkey,
+    pub retracted_by: Pubkey,
+    pub reason: RetractionReason,
+    pub notice_cid: ContentId,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileUpdatedEvent {
+    pub wallet: Pubkey,
+    pub display_name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EditorAddedEvent {
+    pub editor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EditorRemovedEvent {
+    pub editor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposedEvent {
+    pub current_admin: Pubkey,
+    pub proposed_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminAcceptedEvent {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCancelledEvent {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
     pub timestamp: i64,
 }
 
@@ -712,4 +4622,133 @@ pub enum ResearchError {
     
     #[msg("Fee rate too high")]
     FeeTooHigh,
+    
+    #[msg("Invalid admin")]
+    InvalidAdmin,
+    
+    #[msg("No pending admin proposal")]
+    NoPendingAdmin,
+    
+    #[msg("Settings change has no fields set")]
+    EmptySettingsChange,
+    
+    #[msg("Invalid settings delay")]
+    InvalidSettingsDelay,
+    
+    #[msg("Settings change delay has not elapsed")]
+    SettingsChangeNotReady,
+    
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Governance is not initialized")]
+    GovernanceNotInitialized,
+    
+    #[msg("Governance is already initialized")]
+    GovernanceAlreadyInitialized,
+    
+    #[msg("Insufficient governance tokens")]
+    InsufficientGovernanceTokens,
+    
+    #[msg("Invalid parameter value")]
+    InvalidParameterValue,
+    
+    #[msg("Proposal voting has closed")]
+    ProposalVotingClosed,
+    
+    #[msg("Proposal voting is still active")]
+    ProposalVotingActive,
+    
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Quorum not reached")]
+    QuorumNotReached,
+    
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+    
+    #[msg("Account is already at the current version")]
+    AlreadyMigrated,
+    
+    #[msg("Paper update has no fields set")]
+    EmptyPaperUpdate,
+    
+    #[msg("Paper has been retracted")]
+    PaperRetracted,
+    
+    #[msg("Invalid citation")]
+    InvalidCitation,
+    
+    #[msg("Invalid keywords")]
+    InvalidKeywords,
+    
+    #[msg("Invalid display name")]
+    InvalidDisplayName,
+    
+    #[msg("Invalid affiliation")]
+    InvalidAffiliation,
+    
+    #[msg("Invalid ORCID iD")]
+    InvalidOrcid,
+    
+    #[msg("Invalid content identifier")]
+    InvalidContentId,
+    
+    #[msg("Manuscript has already been submitted")]
+    DuplicateManuscript,
+    
+    #[msg("Manuscript registry account is required")]
+    ManuscriptRegistryRequired,
+    
+    #[msg("Commitment does not match the revealed manuscript")]
+    InvalidCommitmentReveal,
+    
+    #[msg("Commitment has already been revealed")]
+    CommitmentAlreadyRevealed,
+    
+    #[msg("Paper status transition is not allowed")]
+    InvalidTransition,
+    
+    #[msg("Funding deadline has not passed")]
+    FundingDeadlineNotPassed,
+    
+    #[msg("Lock duration must be positive, at most the maximum, and not shorten an existing lock")]
+    InvalidLockDuration,
+    
+    #[msg("Tokens are still locked")]
+    LockNotExpired,
+    
+    #[msg("Voter credits account is required in quadratic voting mode")]
+    VoteCreditsRequired,
+    
+    #[msg("Not enough vote credits left this epoch")]
+    InsufficientVoteCredits,
+    
+    #[msg("Governance mint account is required")]
+    GovernanceMintRequired,
+    
+    #[msg("Voting has not opened for this paper")]
+    VotingNotOpen,
+    
+    #[msg("Voting has closed for this paper")]
+    VotingClosed,
+    
+    #[msg("Voting has not closed for this paper")]
+    VotingNotClosed,
+    
+    #[msg("Voting window must open before it closes and close in the future")]
+    InvalidVotingWindow,
+    
+    #[msg("Vote-escrow lock ends before the paper's voting window closes")]
+    LockTooShort,
+    
+    #[msg("Retraction account is required for a retracted paper")]
+    RetractionRequired,
+    
+    #[msg("Paper funds were already claimed by its author")]
+    FundsAlreadyClaimed,
+    
+    #[msg("Paper has no manuscript CID; set one with update_paper")]
+    ManuscriptRequired,
 }
\ No newline at end of file
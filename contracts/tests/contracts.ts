@@ -4,6 +4,7 @@ import { BioxResearch } from "../target/types/biox_research";
 import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
 import { assert, expect } from "chai";
+import { createHash } from "crypto";
 
 describe("biox_research", () => {
   // Configure the client to use the local cluster
@@ -30,15 +31,94 @@ describe("biox_research", () => {
   let platformVaultPda: PublicKey;
   let fundingPda: PublicKey;
   let votePda: PublicKey;
+  let voteEscrowPda: PublicKey;
+  let settingsChangePda: PublicKey;
+  let governanceVaultPda: PublicKey;
   
   // Test data
   const paperData = {
     title: "Revolutionary Gene Therapy Research",
     abstractText: "This paper explores novel approaches to gene therapy using CRISPR-Cas9 technology with enhanced precision and reduced off-target effects.",
-    ipfsHash: "QmXoYLX8dEp2H9B3cK4vN7mR8qS1tU6pW9fG2hJ4lM5nO",
+    ipfsHash: "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
     authors: ["Dr. John Smith", "Dr. Jane Doe"],
     fundingGoal: new anchor.BN(5_000_000), // 5 tokens
     fundingPeriodDays: new anchor.BN(30),
+    category: { genomics: {} },
+    keywords: ["crispr", "gene therapy"],
+  };
+
+  const CATEGORY_PAGE_SIZE = 64;
+
+  // Digest of a CIDv0 (base58 sha2-256 multihash) or a base16 CIDv1 with a one-byte codec
+  const cidDigest = (cid: string) =>
+    Array.from(
+      cid.startsWith("Qm")
+        ? anchor.utils.bytes.bs58.decode(cid).subarray(2)
+        : Buffer.from(cid.slice(1), "hex").subarray(4)
+    );
+
+  // A distinct raw sha2-256 CIDv1 per label, since each manuscript can be submitted once
+  const uniqueCid = (label: string) =>
+    "f01551220" + createHash("sha256").update(label).digest("hex");
+
+  const manuscriptPda = (cid: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("manuscript"), Buffer.from(cidDigest(cid))],
+      program.programId
+    )[0];
+
+  const metadataPda = (paper: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("paper-metadata"), paper.toBuffer()],
+      program.programId
+    )[0];
+
+  // Category index accounts the next publish_paper call for `category` appends to
+  const categoryAccounts = async (category: number) => {
+    const [categoryIndex] = PublicKey.findProgramAddressSync(
+      [Buffer.from("category"), Buffer.from([category])],
+      program.programId
+    );
+    const index = await program.account.categoryIndex.fetchNullable(categoryIndex);
+    const page = index ? Math.floor(index.paperCount.toNumber() / CATEGORY_PAGE_SIZE) : 0;
+    const [categoryPage] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("category-page"),
+        Buffer.from([category]),
+        new anchor.BN(page).toArrayLike(Buffer, "le", 4),
+      ],
+      program.programId
+    );
+    return { categoryIndex, categoryPage };
+  };
+
+  // Papers are seeded by their author and the author's paper nonce
+  const paperPdaFor = (wallet: PublicKey, nonce: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("paper"), wallet.toBuffer(), new anchor.BN(nonce).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const authorIndexPda = (wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("author-index"), wallet.toBuffer()],
+      program.programId
+    )[0];
+
+  const profilePda = (wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("researcher"), wallet.toBuffer()],
+      program.programId
+    )[0];
+
+  // Pause flag bits, mirroring ProgramState::PAUSE_*
+  const PAUSE = {
+    submissions: 1 << 0,
+    funding: 1 << 1,
+    voting: 1 << 2,
+    claims: 1 << 3,
+    reviews: 1 << 4,
+    refunds: 1 << 5,
   };
 
   before(async () => {
@@ -159,7 +239,7 @@ describe("biox_research", () => {
       mint,
       voterTokenAccount,
       admin,
-      5_000_000 // 5 tokens for weighted voting
+      15_000_000 // 15 tokens to lock for voting power
     );
 
     // Derive PDAs
@@ -168,13 +248,10 @@ describe("biox_research", () => {
       program.programId
     );
 
-    [paperPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("paper"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
-      program.programId
-    );
+    paperPda = paperPdaFor(author.publicKey, 0);
 
     [paperTokenAccountPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("paper-token"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      [Buffer.from("paper-token"), paperPdaFor(author.publicKey, 0).toBuffer()],
       program.programId
     );
 
@@ -186,7 +263,7 @@ describe("biox_research", () => {
     [fundingPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("funding"),
-        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        paperPdaFor(author.publicKey, 0).toBuffer(),
         funder.publicKey.toBuffer(),
       ],
       program.programId
@@ -195,11 +272,26 @@ describe("biox_research", () => {
     [votePda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("vote"),
-        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        paperPdaFor(author.publicKey, 0).toBuffer(),
         voter.publicKey.toBuffer(),
       ],
       program.programId
     );
+
+    [voteEscrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vote-escrow"), voter.publicKey.toBuffer()],
+      program.programId
+    );
+
+    [governanceVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("governance-vault")],
+      program.programId
+    );
+
+    [settingsChangePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("settings-change")],
+      program.programId
+    );
   });
 
   describe("Initialize", () => {
@@ -221,7 +313,9 @@ describe("biox_research", () => {
       assert.equal(programState.totalFunding.toNumber(), 0);
       assert.equal(programState.platformFeeRate, 250); // 2.5%
       assert.equal(programState.minFundingGoal.toNumber(), 1_000_000);
-      assert.equal(programState.isPaused, false);
+      assert.equal(programState.pauseFlags, 0);
+      assert.equal(programState.settingsDelay.toNumber(), 2 * 24 * 60 * 60); // 2 days
+      assert.equal(programState.version, 4);
     });
 
     it("Should fail to initialize twice", async () => {
@@ -241,6 +335,32 @@ describe("biox_research", () => {
         expect(error.message).to.include("already in use");
       }
     });
+
+    // Paper votes lock BIOX in the governance vault, so governance is set up before voting
+    it("Admin can initialize governance", async () => {
+      await program.methods
+        .initializeGovernance(
+          new anchor.BN(1_000_000), // 1 token quorum
+          5000, // Simple majority
+          new anchor.BN(5) // 5 second voting period for testing
+        )
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+          governanceMint: mint,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.governanceMint.toString(), mint.toString());
+      assert.equal(programState.governanceQuorum.toNumber(), 1_000_000);
+      assert.equal(programState.governanceThresholdBps, 5000);
+      assert.equal(programState.governanceDecimals, 6);
+    });
   });
 
   describe("Submit Paper", () => {
@@ -252,11 +372,17 @@ describe("biox_research", () => {
           paperData.ipfsHash,
           paperData.authors,
           paperData.fundingGoal,
-          paperData.fundingPeriodDays
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
         )
         .accounts({
           author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
           paper: paperPda,
+          metadata: metadataPda(paperPda),
+          manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
           programState: programStatePda,
           systemProgram: SystemProgram.programId,
         })
@@ -264,20 +390,25 @@ describe("biox_research", () => {
         .rpc();
 
       const paper = await program.account.researchPaper.fetch(paperPda);
-      const programState = await program.account.programState.fetch(programStatePda);
+      const metadata = await program.account.paperMetadata.fetch(metadataPda(paperPda));
+      const authorIndex = await program.account.authorIndex.fetch(authorIndexPda(author.publicKey));
       
-      assert.equal(paper.id.toNumber(), 0);
+      assert.equal(paper.nonce.toNumber(), 0);
       assert.equal(paper.author.toString(), author.publicKey.toString());
-      assert.equal(paper.title, paperData.title);
-      assert.equal(paper.abstractText, paperData.abstractText);
-      assert.equal(paper.ipfsHash, paperData.ipfsHash);
-      assert.deepEqual(paper.authors, paperData.authors);
-      assert.equal(paper.isPublished, false);
+      assert.equal(metadata.paper.toString(), paperPda.toString());
+      assert.equal(metadata.title, paperData.title);
+      assert.equal(metadata.abstractText, paperData.abstractText);
+      assert.equal(paper.contentId.version, 0);
+      assert.deepEqual(Array.from(paper.contentId.digest), cidDigest(paperData.ipfsHash));
+      assert.deepEqual(metadata.authors, paperData.authors);
+      assert.deepEqual(metadata.keywords, paperData.keywords);
+      assert.equal(paper.category.genomics !== undefined, true);
+      assert.equal(paper.status.draft !== undefined, true);
       assert.equal(paper.fundingGoal.toNumber(), paperData.fundingGoal.toNumber());
       assert.equal(paper.fundingCurrent.toNumber(), 0);
       assert.equal(paper.upvotes.toNumber(), 0);
       assert.equal(paper.downvotes.toNumber(), 0);
-      assert.equal(programState.paperCount.toNumber(), 1);
+      assert.equal(authorIndex.paperCount.toNumber(), 1);
     });
 
     it("Should fail with invalid title", async () => {
@@ -289,11 +420,17 @@ describe("biox_research", () => {
             paperData.ipfsHash,
             paperData.authors,
             paperData.fundingGoal,
-            paperData.fundingPeriodDays
+            paperData.fundingPeriodDays,
+            paperData.category,
+            paperData.keywords
           )
           .accounts({
             author: author.publicKey,
+            authorIndex: authorIndexPda(author.publicKey),
+            authorProfile: profilePda(author.publicKey),
             paper: paperPda,
+            metadata: metadataPda(paperPda),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
             programState: programStatePda,
             systemProgram: SystemProgram.programId,
           })
@@ -306,11 +443,110 @@ describe("biox_research", () => {
       }
     });
 
+    it("Should fail with a malformed content identifier", async () => {
+      try {
+        await program.methods
+          .submitPaper(
+            paperData.title,
+            paperData.abstractText,
+            "QmXoYLX8dEp2H9B3cK4vN7mR8qS1tU6pW9fG2hJ4lM5nO", // 'l' and 'O' are not base58
+            paperData.authors,
+            paperData.fundingGoal,
+            paperData.fundingPeriodDays,
+            paperData.category,
+            paperData.keywords
+          )
+          .accounts({
+            author: author.publicKey,
+            authorIndex: authorIndexPda(author.publicKey),
+            authorProfile: profilePda(author.publicKey),
+            paper: paperPdaFor(author.publicKey, 1),
+            metadata: metadataPda(paperPdaFor(author.publicKey, 1)),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidContentId");
+      }
+    });
+
+    it("Should fail to submit an already registered manuscript", async () => {
+      const registry = await program.account.manuscriptRegistry.fetch(manuscriptPda(paperData.ipfsHash));
+      assert.equal(registry.paper.toString(), paperPda.toString());
+      assert.equal(registry.submitter.toString(), author.publicKey.toString());
+
+      try {
+        await program.methods
+          .submitPaper(
+            paperData.title,
+            paperData.abstractText,
+            paperData.ipfsHash,
+            paperData.authors,
+            paperData.fundingGoal,
+            paperData.fundingPeriodDays,
+            paperData.category,
+            paperData.keywords
+          )
+          .accounts({
+            author: funder.publicKey,
+            authorIndex: authorIndexPda(funder.publicKey),
+            authorProfile: profilePda(funder.publicKey),
+            paper: paperPdaFor(funder.publicKey, 0),
+            metadata: metadataPda(paperPdaFor(funder.publicKey, 0)),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("DuplicateManuscript");
+      }
+    });
+
+    it("Should fail with too many keywords", async () => {
+      const invalidPaperPda = paperPdaFor(funder.publicKey, 0);
+
+      try {
+        await program.methods
+          .submitPaper(
+            "Test Paper",
+            paperData.abstractText,
+            paperData.ipfsHash,
+            paperData.authors,
+            paperData.fundingGoal,
+            paperData.fundingPeriodDays,
+            paperData.category,
+            ["one", "two", "three", "four", "five", "six"] // Max 5
+          )
+          .accounts({
+            author: author.publicKey,
+            authorIndex: authorIndexPda(author.publicKey),
+            authorProfile: profilePda(author.publicKey),
+            paper: invalidPaperPda,
+            metadata: metadataPda(invalidPaperPda),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidKeywords");
+      }
+    });
+
     it("Should fail with funding goal too low", async () => {
-      const [invalidPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+      const invalidPaperPda = paperPdaFor(funder.publicKey, 0);
 
       try {
         await program.methods
@@ -320,11 +556,17 @@ describe("biox_research", () => {
             paperData.ipfsHash,
             paperData.authors,
             new anchor.BN(500_000), // Below minimum
-            paperData.fundingPeriodDays
+            paperData.fundingPeriodDays,
+            paperData.category,
+            paperData.keywords
           )
           .accounts({
             author: author.publicKey,
+            authorIndex: authorIndexPda(author.publicKey),
+            authorProfile: profilePda(author.publicKey),
             paper: invalidPaperPda,
+            metadata: metadataPda(invalidPaperPda),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
             programState: programStatePda,
             systemProgram: SystemProgram.programId,
           })
@@ -341,39 +583,64 @@ describe("biox_research", () => {
   describe("Publish Paper", () => {
     it("Author can publish their own paper", async () => {
       await program.methods
-        .publishPaper(new anchor.BN(0))
+        .publishPaper()
         .accounts({
           authority: author.publicKey,
           paper: paperPda,
+          authorProfile: profilePda(author.publicKey),
           programState: programStatePda,
+          ...(await categoryAccounts(0)),
+          systemProgram: SystemProgram.programId,
         })
         .signers([author])
         .rpc();
 
       const paper = await program.account.researchPaper.fetch(paperPda);
-      assert.equal(paper.isPublished, true);
       assert.equal(paper.status.published !== undefined, true);
+      assert.equal(
+        paper.votingClosesAt.toNumber() - paper.votingOpensAt.toNumber(),
+        30 * 24 * 60 * 60 // Program default window
+      );
     });
 
-    it("Should fail when non-author tries to publish", async () => {
-      const [secondPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
+    it("Indexes published papers by category", async () => {
+      const [categoryIndex] = PublicKey.findProgramAddressSync(
+        [Buffer.from("category"), Buffer.from([0])],
+        program.programId
+      );
+      const [firstPage] = PublicKey.findProgramAddressSync(
+        [Buffer.from("category-page"), Buffer.from([0]), new anchor.BN(0).toArrayLike(Buffer, "le", 4)],
         program.programId
       );
 
+      const index = await program.account.categoryIndex.fetch(categoryIndex);
+      const page = await program.account.categoryIndexPage.fetch(firstPage);
+      assert.equal(index.paperCount.toNumber(), 1);
+      assert.equal(page.papers[0].toString(), paperPda.toString());
+    });
+
+    it("Should fail when non-author tries to publish", async () => {
+      const secondPaperPda = paperPdaFor(funder.publicKey, 0);
+
       // First submit a paper with different author
       await program.methods
         .submitPaper(
           "Second Paper",
           paperData.abstractText,
-          paperData.ipfsHash,
+          uniqueCid("secondPaper"),
           paperData.authors,
           paperData.fundingGoal,
-          paperData.fundingPeriodDays
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
         )
         .accounts({
           author: funder.publicKey, // Different author
+          authorIndex: authorIndexPda(funder.publicKey),
+          authorProfile: profilePda(funder.publicKey),
           paper: secondPaperPda,
+          metadata: metadataPda(secondPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("secondPaper")),
           programState: programStatePda,
           systemProgram: SystemProgram.programId,
         })
@@ -382,11 +649,14 @@ describe("biox_research", () => {
 
       try {
         await program.methods
-          .publishPaper(new anchor.BN(1))
+          .publishPaper()
           .accounts({
             authority: author.publicKey, // Wrong authority
             paper: secondPaperPda,
+            authorProfile: profilePda(funder.publicKey),
             programState: programStatePda,
+            ...(await categoryAccounts(0)),
+            systemProgram: SystemProgram.programId,
           })
           .signers([author])
           .rpc();
@@ -398,23 +668,23 @@ describe("biox_research", () => {
     });
 
     it("Admin can publish any paper", async () => {
-      const [secondPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+      const secondPaperPda = paperPdaFor(funder.publicKey, 0);
 
       await program.methods
-        .publishPaper(new anchor.BN(1))
+        .publishPaper()
         .accounts({
           authority: admin.publicKey, // Admin authority
           paper: secondPaperPda,
+          authorProfile: profilePda(funder.publicKey),
           programState: programStatePda,
+          ...(await categoryAccounts(0)),
+          systemProgram: SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
 
       const paper = await program.account.researchPaper.fetch(secondPaperPda);
-      assert.equal(paper.isPublished, true);
+      assert.equal(paper.status.published !== undefined, true);
     });
   });
 
@@ -443,10 +713,11 @@ describe("biox_research", () => {
       const fundAmount = new anchor.BN(2_000_000); // 2 tokens
       
       await program.methods
-        .fundPaper(new anchor.BN(0), fundAmount)
+        .fundPaper(fundAmount)
         .accounts({
           funder: funder.publicKey,
           paper: paperPda,
+          authorProfile: profilePda(author.publicKey),
           funderTokenAccount: funderTokenAccount,
           paperTokenAccount: paperTokenAccountPda,
           platformTokenAccount: platformVaultPda,
@@ -481,24 +752,27 @@ describe("biox_research", () => {
     });
 
     it("Should fail to fund unpublished paper", async () => {
-      const [unpublishedPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(2).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+      const unpublishedPaperPda = paperPdaFor(author.publicKey, 1);
 
       // Submit but don't publish
       await program.methods
         .submitPaper(
           "Unpublished Paper",
           paperData.abstractText,
-          paperData.ipfsHash,
+          uniqueCid("unpublishedPaper"),
           paperData.authors,
           paperData.fundingGoal,
-          paperData.fundingPeriodDays
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
         )
         .accounts({
           author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
           paper: unpublishedPaperPda,
+          metadata: metadataPda(unpublishedPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("unpublishedPaper")),
           programState: programStatePda,
           systemProgram: SystemProgram.programId,
         })
@@ -508,7 +782,7 @@ describe("biox_research", () => {
       const [unpublishedFundingPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("funding"),
-          new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+          paperPdaFor(author.publicKey, 1).toBuffer(),
           funder.publicKey.toBuffer(),
         ],
         program.programId
@@ -516,10 +790,11 @@ describe("biox_research", () => {
 
       try {
         await program.methods
-          .fundPaper(new anchor.BN(2), new anchor.BN(1_000_000))
+          .fundPaper(new anchor.BN(1_000_000))
           .accounts({
             funder: funder.publicKey,
             paper: unpublishedPaperPda,
+            authorProfile: profilePda(author.publicKey),
             funderTokenAccount: funderTokenAccount,
             paperTokenAccount: paperTokenAccountPda,
             platformTokenAccount: platformVaultPda,
@@ -543,7 +818,7 @@ describe("biox_research", () => {
       const [secondFundingPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("funding"),
-          new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+          paperPdaFor(author.publicKey, 0).toBuffer(),
           voter.publicKey.toBuffer(), // Using voter as second funder
         ],
         program.programId
@@ -560,10 +835,11 @@ describe("biox_research", () => {
       );
 
       await program.methods
-        .fundPaper(new anchor.BN(0), remainingAmount)
+        .fundPaper(remainingAmount)
         .accounts({
           funder: voter.publicKey,
           paper: paperPda,
+          authorProfile: profilePda(author.publicKey),
           funderTokenAccount: voterTokenAccount,
           paperTokenAccount: paperTokenAccountPda,
           platformTokenAccount: platformVaultPda,
@@ -580,727 +856,3280 @@ describe("biox_research", () => {
     });
   });
 
-  describe("Vote Paper", () => {
-    it("Successfully votes on a published paper with weight", async () => {
+  describe("Vote Escrow", () => {
+    const MAX_LOCK_DURATION = 4 * 365 * 24 * 60 * 60;
+
+    it("Voter locks BIOX for voting power", async () => {
       await program.methods
-        .votePaper(new anchor.BN(0), true) // Upvote
+        .lockTokens(new anchor.BN(10_000_000), new anchor.BN(MAX_LOCK_DURATION))
         .accounts({
-          voter: voter.publicKey,
-          paper: paperPda,
+          owner: voter.publicKey,
           programState: programStatePda,
-          voterTokenAccount: voterTokenAccount,
-          vote: votePda,
+          voteEscrow: voteEscrowPda,
+          ownerTokenAccount: voterTokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([voter])
         .rpc();
 
-      const paper = await program.account.researchPaper.fetch(paperPda);
-      const vote = await program.account.vote.fetch(votePda);
-
-      // Voter has 5+ tokens, so should get weighted vote (at least 5x weight)
-      assert.isTrue(paper.upvotes.toNumber() >= 5);
-      assert.equal(vote.isUpvote, true);
-      assert.equal(vote.voter.toString(), voter.publicKey.toString());
-      assert.isTrue(vote.weight.toNumber() >= 5);
-    });
-
-    it("Should fail to vote on unpublished paper", async () => {
-      const [unpublishedPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(2).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
-
-      const [unpublishedVotePda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vote"),
-          new anchor.BN(2).toArrayLike(Buffer, "le", 8),
-          voter.publicKey.toBuffer(),
-        ],
-        program.programId
+      const escrow = await program.account.voteEscrow.fetch(voteEscrowPda);
+      assert.equal(escrow.owner.toString(), voter.publicKey.toString());
+      assert.equal(escrow.amount.toNumber(), 10_000_000);
+      assert.equal(
+        escrow.unlockAt.toNumber() - escrow.lockedAt.toNumber(),
+        MAX_LOCK_DURATION
       );
+    });
 
+    it("Should fail to shorten an existing lock", async () => {
       try {
         await program.methods
-          .votePaper(new anchor.BN(2), true)
+          .lockTokens(new anchor.BN(0), new anchor.BN(24 * 60 * 60))
           .accounts({
-            voter: voter.publicKey,
-            paper: unpublishedPaperPda,
+            owner: voter.publicKey,
             programState: programStatePda,
-            voterTokenAccount: voterTokenAccount,
-            vote: unpublishedVotePda,
+            voteEscrow: voteEscrowPda,
+            ownerTokenAccount: voterTokenAccount,
+            governanceVault: governanceVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
           .signers([voter])
           .rpc();
-        
+
         assert.fail("Should have failed");
       } catch (error) {
-        expect(error.message).to.include("PaperNotPublished");
+        expect(error.message).to.include("InvalidLockDuration");
       }
     });
-  });
-
-  describe("Claim Funds", () => {
-    it("Author can claim funds when paper is fully funded", async () => {
-      const paperTokenBalanceBefore = await getAccount(provider.connection, paperTokenAccountPda);
-      const authorTokenBalanceBefore = await getAccount(provider.connection, authorTokenAccount);
-
-      await program.methods
-        .claimFunds(new anchor.BN(0))
-        .accounts({
-          author: author.publicKey,
-          paper: paperPda,
-          paperTokenAccount: paperTokenAccountPda,
-          authorTokenAccount: authorTokenAccount,
-          programState: programStatePda,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([author])
-        .rpc();
-
-      const paper = await program.account.researchPaper.fetch(paperPda);
-      const paperTokenBalanceAfter = await getAccount(provider.connection, paperTokenAccountPda);
-      const authorTokenBalanceAfter = await getAccount(provider.connection, authorTokenAccount);
-
-      assert.equal(paper.status.completed !== undefined, true);
-      assert.equal(Number(paperTokenBalanceAfter.amount), 0);
-      assert.isTrue(
-        Number(authorTokenBalanceAfter.amount) > Number(authorTokenBalanceBefore.amount)
-      );
-    });
 
-    it("Should fail when non-author tries to claim", async () => {
+    it("Should fail to withdraw before the lock expires", async () => {
       try {
         await program.methods
-          .claimFunds(new anchor.BN(0))
+          .withdrawLock()
           .accounts({
-            author: funder.publicKey, // Wrong author
-            paper: paperPda,
-            paperTokenAccount: paperTokenAccountPda,
-            authorTokenAccount: funderTokenAccount,
+            owner: voter.publicKey,
             programState: programStatePda,
+            voteEscrow: voteEscrowPda,
+            ownerTokenAccount: voterTokenAccount,
+            governanceVault: governanceVaultPda,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
-          .signers([funder])
+          .signers([voter])
           .rpc();
-        
+
         assert.fail("Should have failed");
       } catch (error) {
-        expect(error.message).to.include("Unauthorized");
+        expect(error.message).to.include("LockNotExpired");
       }
     });
-  });
 
-  describe("Admin Functions", () => {
-    it("Admin can pause the program", async () => {
+    const funderEscrowPda = () =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("vote-escrow"), funder.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+    it("Should fail to vote with a lock that ends before voting closes", async () => {
       await program.methods
-        .togglePause()
+        .lockTokens(new anchor.BN(1_000_000), new anchor.BN(1))
         .accounts({
-          admin: admin.publicKey,
+          owner: funder.publicKey,
           programState: programStatePda,
+          voteEscrow: funderEscrowPda(),
+          ownerTokenAccount: funderTokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
-        .signers([admin])
+        .signers([funder])
         .rpc();
 
-      const programState = await program.account.programState.fetch(programStatePda);
-      assert.equal(programState.isPaused, true);
-    });
-
-    it("Should fail operations when paused", async () => {
-      const [pausedPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(3).toArrayLike(Buffer, "le", 8)],
+      const [funderVotePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), paperPda.toBuffer(), funder.publicKey.toBuffer()],
         program.programId
       );
 
       try {
         await program.methods
-          .submitPaper(
-            "Paused Paper",
-            paperData.abstractText,
-            paperData.ipfsHash,
-            paperData.authors,
-            paperData.fundingGoal,
-            paperData.fundingPeriodDays
-          )
+          .votePaper(true, new anchor.BN(0))
           .accounts({
-            author: author.publicKey,
-            paper: pausedPaperPda,
+            voter: funder.publicKey,
+            paper: paperPda,
             programState: programStatePda,
+            voteEscrow: funderEscrowPda(),
+            voterCredits: null,
+            vote: funderVotePda,
             systemProgram: SystemProgram.programId,
           })
-          .signers([author])
+          .signers([funder])
           .rpc();
-        
+
         assert.fail("Should have failed");
       } catch (error) {
-        expect(error.message).to.include("ProgramPaused");
+        expect(error.message).to.include("LockTooShort");
       }
     });
 
-    it("Admin can unpause the program", async () => {
+    it("Owner withdraws an expired lock", async () => {
+      const escrowPda = funderEscrowPda();
+      const balanceBefore = await getAccount(provider.connection, funderTokenAccount);
+
+      await sleep(2000);
+
       await program.methods
-        .togglePause()
+        .withdrawLock()
         .accounts({
-          admin: admin.publicKey,
+          owner: funder.publicKey,
           programState: programStatePda,
+          voteEscrow: escrowPda,
+          ownerTokenAccount: funderTokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
-        .signers([admin])
+        .signers([funder])
         .rpc();
 
-      const programState = await program.account.programState.fetch(programStatePda);
-      assert.equal(programState.isPaused, false);
+      assert.isNull(await program.account.voteEscrow.fetchNullable(escrowPda));
+      const balanceAfter = await getAccount(provider.connection, funderTokenAccount);
+      assert.equal(balanceAfter.amount, balanceBefore.amount + BigInt(1_000_000));
     });
+  });
 
-    it("Admin can update settings", async () => {
+  describe("Vote Paper", () => {
+    it("Successfully votes on a published paper with weight", async () => {
       await program.methods
-        .updateSettings(300, new anchor.BN(2_000_000)) // 3% fee, 2 token minimum
+        .votePaper(true, new anchor.BN(0)) // Upvote
         .accounts({
-          admin: admin.publicKey,
+          voter: voter.publicKey,
+          paper: paperPda,
           programState: programStatePda,
-        })
-        .signers([admin])
+          voteEscrow: voteEscrowPda,
+          voterCredits: null,
+          vote: votePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
         .rpc();
 
-      const programState = await program.account.programState.fetch(programStatePda);
-      assert.equal(programState.platformFeeRate, 300);
-      assert.equal(programState.minFundingGoal.toNumber(), 2_000_000);
-    });
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const vote = await program.account.vote.fetch(votePda);
 
-    it("Should fail when non-admin tries to update settings", async () => {
-      try {
-        await program.methods
-          .updateSettings(400, new anchor.BN(3_000_000))
-          .accounts({
-            admin: author.publicKey, // Non-admin
-            programState: programStatePda,
-          })
-          .signers([author])
-          .rpc();
-        
-        assert.fail("Should have failed");
-      } catch (error) {
-        expect(error.message).to.include("Unauthorized");
-      }
+      // Voter locked 10 tokens for the maximum duration, so should get at least 5x weight
+      assert.isTrue(paper.upvotes.toNumber() >= 5);
+      assert.equal(vote.isUpvote, true);
+      assert.equal(vote.voter.toString(), voter.publicKey.toString());
+      assert.isTrue(vote.weight.toNumber() >= 5);
     });
 
-    it("Should fail with fee rate too high", async () => {
-      try {
-        await program.methods
-          .updateSettings(1500, null) // 15% - too high
-          .accounts({
-            admin: admin.publicKey,
-            programState: programStatePda,
-          })
-          .signers([admin])
-          .rpc();
-        
-        assert.fail("Should have failed");
-      } catch (error) {
-        expect(error.message).to.include("FeeTooHigh");
-      }
+    it("Voter can flip their vote", async () => {
+      const before = await program.account.researchPaper.fetch(paperPda);
+      const voteBefore = await program.account.vote.fetch(votePda);
+
+      await program.methods
+        .changeVote(false)
+        .accounts({
+          voter: voter.publicKey,
+          paper: paperPda,
+          programState: programStatePda,
+          voteEscrow: voteEscrowPda,
+          vote: votePda,
+        })
+        .signers([voter])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const vote = await program.account.vote.fetch(votePda);
+      assert.equal(vote.isUpvote, false);
+      assert.equal(
+        paper.upvotes.toNumber(),
+        before.upvotes.toNumber() - voteBefore.weight.toNumber()
+      );
+      assert.equal(
+        paper.downvotes.toNumber(),
+        before.downvotes.toNumber() + vote.weight.toNumber()
+      );
     });
-  });
 
-  describe("Edge Cases and Error Handling", () => {
-    it("Should handle maximum string lengths", async () => {
-      const longTitle = "A".repeat(100); // Max length
-      const longAbstract = "B".repeat(1000); // Max length
-      const longIpfsHash = "C".repeat(100); // Max length
-      const maxAuthors = Array(10).fill(0).map((_, i) => `Author${i}`); // Max 10 authors
+    it("Voter can retract and re-cast their vote", async () => {
+      const before = await program.account.researchPaper.fetch(paperPda);
+      const voteBefore = await program.account.vote.fetch(votePda);
 
-      const [maxLengthPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(3).toArrayLike(Buffer, "le", 8)],
-        program.programId
+      await program.methods
+        .retractVote()
+        .accounts({
+          voter: voter.publicKey,
+          paper: paperPda,
+          programState: programStatePda,
+          vote: votePda,
+        })
+        .signers([voter])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      assert.isNull(await program.account.vote.fetchNullable(votePda));
+      assert.equal(
+        paper.downvotes.toNumber(),
+        before.downvotes.toNumber() - voteBefore.weight.toNumber()
       );
 
       await program.methods
-        .submitPaper(
-          longTitle,
-          longAbstract,
-          longIpfsHash,
-          maxAuthors,
-          paperData.fundingGoal,
-          paperData.fundingPeriodDays
-        )
+        .votePaper(true, new anchor.BN(0))
         .accounts({
-          author: author.publicKey,
-          paper: maxLengthPaperPda,
+          voter: voter.publicKey,
+          paper: paperPda,
           programState: programStatePda,
+          voteEscrow: voteEscrowPda,
+          voterCredits: null,
+          vote: votePda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([author])
+        .signers([voter])
         .rpc();
 
-      const paper = await program.account.researchPaper.fetch(maxLengthPaperPda);
-      assert.equal(paper.title, longTitle);
-      assert.equal(paper.abstractText, longAbstract);
-      assert.equal(paper.ipfsHash, longIpfsHash);
-      assert.equal(paper.authors.length, 10);
+      const recast = await program.account.researchPaper.fetch(paperPda);
+      const vote = await program.account.vote.fetch(votePda);
+      assert.equal(vote.isUpvote, true);
+      assert.equal(
+        recast.upvotes.toNumber(),
+        paper.upvotes.toNumber() + vote.weight.toNumber()
+      );
     });
 
-    it("Should fail with too long strings", async () => {
-      const tooLongTitle = "A".repeat(101); // Too long
+    it("Should fail to vote on unpublished paper", async () => {
+      const unpublishedPaperPda = paperPdaFor(author.publicKey, 1);
 
-      const [tooLongPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(4).toArrayLike(Buffer, "le", 8)],
+      const [unpublishedVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          paperPdaFor(author.publicKey, 1).toBuffer(),
+          voter.publicKey.toBuffer(),
+        ],
         program.programId
       );
 
       try {
         await program.methods
-          .submitPaper(
-            tooLongTitle,
-            paperData.abstractText,
-            paperData.ipfsHash,
-            paperData.authors,
-            paperData.fundingGoal,
-            paperData.fundingPeriodDays
-          )
+          .votePaper(true, new anchor.BN(0))
           .accounts({
-            author: author.publicKey,
-            paper: tooLongPaperPda,
+            voter: voter.publicKey,
+            paper: unpublishedPaperPda,
             programState: programStatePda,
+            voteEscrow: voteEscrowPda,
+            voterCredits: null,
+            vote: unpublishedVotePda,
             systemProgram: SystemProgram.programId,
           })
-          .signers([author])
+          .signers([voter])
           .rpc();
         
         assert.fail("Should have failed");
       } catch (error) {
-        expect(error.message).to.include("InvalidTitle");
+        expect(error.message).to.include("PaperNotPublished");
       }
     });
+  });
 
-    it("Should handle zero token balance voting", async () => {
-      const zeroBalanceVoter = Keypair.generate();
-      await provider.connection.requestAirdrop(zeroBalanceVoter.publicKey, LAMPORTS_PER_SOL);
-      await new Promise(resolve => setTimeout(resolve, 1000));
+  describe("Update Paper", () => {
+    let draftPaperPda: PublicKey;
 
-      const zeroBalanceTokenAccount = await createAccount(
-        provider.connection,
-        zeroBalanceVoter,
-        mint,
-        zeroBalanceVoter.publicKey
-      );
+    before(async () => {
+      // Paper 2 was submitted but never published
+      draftPaperPda = paperPdaFor(author.publicKey, 1);
+    });
 
-      const [zeroBalanceVotePda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vote"),
-          new anchor.BN(1).toArrayLike(Buffer, "le", 8), // Vote on second paper
-          zeroBalanceVoter.publicKey.toBuffer(),
-        ],
-        program.programId
-      );
+    it("Author can update a draft paper and the metadata account is resized", async () => {
+      const accountBefore = await provider.connection.getAccountInfo(metadataPda(draftPaperPda));
+      const newTitle = "Unpublished Paper, Revised Edition With A Longer Title";
+      const newAuthors = ["Dr. John Smith", "Dr. Jane Doe", "Dr. Alex Newcomer"];
 
       await program.methods
-        .votePaper(new anchor.BN(1), false) // Downvote
+        .updatePaper(newTitle, null, null, newAuthors, null)
         .accounts({
-          voter: zeroBalanceVoter.publicKey,
-          paper: PublicKey.findProgramAddressSync(
-            [Buffer.from("paper"), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
-            program.programId
-          )[0],
+          author: author.publicKey,
+          paper: draftPaperPda,
+          metadata: metadataPda(draftPaperPda),
+          manuscriptRegistry: null,
+          previousManuscriptRegistry: null,
           programState: programStatePda,
-          voterTokenAccount: zeroBalanceTokenAccount,
-          vote: zeroBalanceVotePda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([zeroBalanceVoter])
+        .signers([author])
         .rpc();
 
-      const vote = await program.account.vote.fetch(zeroBalanceVotePda);
-      assert.equal(vote.weight.toNumber(), 1); // Should default to weight 1
-      assert.equal(vote.isUpvote, false);
-    });
+      const metadata = await program.account.paperMetadata.fetch(metadataPda(draftPaperPda));
+      const accountAfter = await provider.connection.getAccountInfo(metadataPda(draftPaperPda));
 
-    it("Should handle funding deadline expiration", async () => {
-      // Create a paper with very short funding period
-      const [expiredPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(5).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+      assert.equal(metadata.title, newTitle);
+      assert.equal(metadata.abstractText, paperData.abstractText);
+      assert.deepEqual(metadata.authors, newAuthors);
+      assert.isTrue(accountAfter.data.length > accountBefore.data.length);
 
+      // Shrinking the metadata shrinks the account again
       await program.methods
-        .submitPaper(
-          "Expired Paper",
-          paperData.abstractText,
-          paperData.ipfsHash,
-          paperData.authors,
-          paperData.fundingGoal,
-          new anchor.BN(1) // 1 day funding period
-        )
+        .updatePaper("Unpublished Paper", null, null, paperData.authors, null)
         .accounts({
           author: author.publicKey,
-          paper: expiredPaperPda,
+          paper: draftPaperPda,
+          metadata: metadataPda(draftPaperPda),
+          manuscriptRegistry: null,
+          previousManuscriptRegistry: null,
           programState: programStatePda,
           systemProgram: SystemProgram.programId,
         })
         .signers([author])
         .rpc();
 
-      await program.methods
-        .publishPaper(new anchor.BN(5))
-        .accounts({
-          authority: author.publicKey,
-          paper: expiredPaperPda,
-          programState: programStatePda,
-        })
-        .signers([author])
-        .rpc();
-
-      // Note: In a real test, you would need to wait for the deadline to pass
-      // or manipulate the clock. For this test, we'll just verify the paper was created
-      const paper = await program.account.researchPaper.fetch(expiredPaperPda);
-      assert.isTrue(paper.fundingDeadline > 0);
+      const accountShrunk = await provider.connection.getAccountInfo(metadataPda(draftPaperPda));
+      assert.equal(accountShrunk.data.length, accountBefore.data.length);
     });
-  });
-
-  describe("Integration Tests", () => {
-    it("Complete workflow: submit -> publish -> fund -> vote -> claim", async () => {
-      const workflow = {
-        title: "Complete Workflow Paper",
-        abstractText: "Testing the complete research paper workflow from submission to completion.",
-        ipfsHash: "QmWorkflowHash123456789",
-        authors: ["Dr. Workflow"],
-        fundingGoal: new anchor.BN(3_000_000),
-        fundingPeriodDays: new anchor.BN(60),
-      };
-
-      // Step 1: Submit paper
-      const [workflowPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(6).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
 
+    it("Author can replace the keywords of a draft paper", async () => {
       await program.methods
-        .submitPaper(
-          workflow.title,
-          workflow.abstractText,
-          workflow.ipfsHash,
-          workflow.authors,
-          workflow.fundingGoal,
-          workflow.fundingPeriodDays
-        )
+        .updatePaper(null, null, null, null, ["proteomics"])
         .accounts({
           author: author.publicKey,
-          paper: workflowPaperPda,
+          paper: draftPaperPda,
+          metadata: metadataPda(draftPaperPda),
+          manuscriptRegistry: null,
+          previousManuscriptRegistry: null,
           programState: programStatePda,
           systemProgram: SystemProgram.programId,
         })
         .signers([author])
         .rpc();
 
-      let paper = await program.account.researchPaper.fetch(workflowPaperPda);
-      assert.equal(paper.status.draft !== undefined, true);
+      const paper = await program.account.researchPaper.fetch(draftPaperPda);
+      const metadata = await program.account.paperMetadata.fetch(metadataPda(draftPaperPda));
+      assert.deepEqual(metadata.keywords, ["proteomics"]);
+      assert.equal(paper.category.genomics !== undefined, true);
+    });
+
+    it("Author can replace the manuscript of a draft paper", async () => {
+      const newCid = uniqueCid("unpublishedPaperV2");
 
-      // Step 2: Publish paper
       await program.methods
-        .publishPaper(new anchor.BN(6))
+        .updatePaper(null, null, newCid, null, null)
         .accounts({
-          authority: author.publicKey,
-          paper: workflowPaperPda,
+          author: author.publicKey,
+          paper: draftPaperPda,
+          metadata: metadataPda(draftPaperPda),
+          manuscriptRegistry: manuscriptPda(newCid),
+          previousManuscriptRegistry: manuscriptPda(uniqueCid("unpublishedPaper")),
           programState: programStatePda,
+          systemProgram: SystemProgram.programId,
         })
         .signers([author])
         .rpc();
 
-      paper = await program.account.researchPaper.fetch(workflowPaperPda);
-      assert.equal(paper.status.published !== undefined, true);
+      const paper = await program.account.researchPaper.fetch(draftPaperPda);
+      const registry = await program.account.manuscriptRegistry.fetch(manuscriptPda(newCid));
+      assert.deepEqual(Array.from(paper.contentId.digest), cidDigest(newCid));
+      assert.equal(registry.paper.toString(), draftPaperPda.toString());
 
-      // Step 3: Vote on paper
-      const [workflowVotePda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vote"),
-          new anchor.BN(6).toArrayLike(Buffer, "le", 8),
-          voter.publicKey.toBuffer(),
-        ],
-        program.programId
+      // The replaced manuscript is released so it can be submitted again
+      assert.isNull(
+        await program.account.manuscriptRegistry.fetchNullable(
+          manuscriptPda(uniqueCid("unpublishedPaper"))
+        )
       );
+    });
+
+    it("Re-submitting the current manuscript leaves it registered", async () => {
+      const cid = uniqueCid("unpublishedPaperV2");
 
       await program.methods
-        .votePaper(new anchor.BN(6), true)
+        .updatePaper(null, null, cid, null, null)
         .accounts({
-          voter: voter.publicKey,
-          paper: workflowPaperPda,
+          author: author.publicKey,
+          paper: draftPaperPda,
+          metadata: metadataPda(draftPaperPda),
+          manuscriptRegistry: manuscriptPda(cid),
+          previousManuscriptRegistry: null,
           programState: programStatePda,
-          voterTokenAccount: voterTokenAccount,
-          vote: workflowVotePda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([voter])
+        .signers([author])
         .rpc();
 
-      paper = await program.account.researchPaper.fetch(workflowPaperPda);
-      assert.isTrue(paper.upvotes.toNumber() > 0);
-
-      // Step 4: Fund paper to completion
-      const [workflowPaperTokenPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper-token"), new anchor.BN(6).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+      const paper = await program.account.researchPaper.fetch(draftPaperPda);
+      const registry = await program.account.manuscriptRegistry.fetch(manuscriptPda(cid));
+      assert.deepEqual(Array.from(paper.contentId.digest), cidDigest(cid));
+      assert.equal(registry.paper.toString(), draftPaperPda.toString());
+    });
 
-      const [workflowFundingPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("funding"),
-          new anchor.BN(6).toArrayLike(Buffer, "le", 8),
-          funder.publicKey.toBuffer(),
-        ],
-        program.programId
-      );
+    it("Should fail to update with an invalid title", async () => {
+      try {
+        await program.methods
+          .updatePaper("", null, null, null, null)
+          .accounts({
+            author: author.publicKey,
+            paper: draftPaperPda,
+            metadata: metadataPda(draftPaperPda),
+            manuscriptRegistry: null,
+            previousManuscriptRegistry: null,
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
 
-      // Create paper token account
-      await createAccount(
-        provider.connection,
-        admin,
-        mint,
-        workflowPaperTokenPda,
-        admin
-      );
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTitle");
+      }
+    });
 
-      await program.methods
-        .fundPaper(new anchor.BN(6), workflow.fundingGoal)
+    it("Should fail when non-author tries to update", async () => {
+      try {
+        await program.methods
+          .updatePaper("Hijacked Title", null, null, null, null)
+          .accounts({
+            author: funder.publicKey,
+            paper: draftPaperPda,
+            metadata: metadataPda(draftPaperPda),
+            manuscriptRegistry: null,
+            previousManuscriptRegistry: null,
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail to update a published paper", async () => {
+      try {
+        await program.methods
+          .updatePaper("New Title", null, null, null, null)
+          .accounts({
+            author: author.publicKey,
+            paper: paperPda,
+            metadata: metadataPda(paperPda),
+            manuscriptRegistry: null,
+            previousManuscriptRegistry: null,
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaperStatus");
+      }
+    });
+  });
+
+  describe("Claim Funds", () => {
+    it("Author can claim funds when paper is fully funded", async () => {
+      const paperTokenBalanceBefore = await getAccount(provider.connection, paperTokenAccountPda);
+      const authorTokenBalanceBefore = await getAccount(provider.connection, authorTokenAccount);
+
+      await program.methods
+        .claimFunds()
+        .accounts({
+          author: author.publicKey,
+          paper: paperPda,
+          authorProfile: profilePda(author.publicKey),
+          paperTokenAccount: paperTokenAccountPda,
+          authorTokenAccount: authorTokenAccount,
+          programState: programStatePda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const paperTokenBalanceAfter = await getAccount(provider.connection, paperTokenAccountPda);
+      const authorTokenBalanceAfter = await getAccount(provider.connection, authorTokenAccount);
+
+      assert.equal(paper.status.completed !== undefined, true);
+      assert.equal(Number(paperTokenBalanceAfter.amount), 0);
+      assert.isTrue(
+        Number(authorTokenBalanceAfter.amount) > Number(authorTokenBalanceBefore.amount)
+      );
+    });
+
+    it("Should fail when non-author tries to claim", async () => {
+      try {
+        await program.methods
+          .claimFunds()
+          .accounts({
+            author: funder.publicKey, // Wrong author
+            paper: paperPda,
+            authorProfile: profilePda(author.publicKey),
+            paperTokenAccount: paperTokenAccountPda,
+            authorTokenAccount: funderTokenAccount,
+            programState: programStatePda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Researcher Profiles", () => {
+    it("Tracks author stats across submit, publish, fund and claim", async () => {
+      const profile = await program.account.researcherProfile.fetch(profilePda(author.publicKey));
+
+      assert.equal(profile.wallet.toString(), author.publicKey.toString());
+      assert.isAtLeast(profile.papersSubmitted, 2);
+      assert.isAtLeast(profile.papersPublished, 1);
+      assert.equal(profile.papersCompleted, 1);
+      assert.equal(profile.totalFundingRaised.toNumber() > 0, true);
+      assert.equal(
+        profile.reputation.toNumber(),
+        profile.papersPublished * 10 + profile.papersCompleted * 25
+      );
+    });
+
+    it("Author can set their profile details", async () => {
+      const bioCid = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+      await program.methods
+        .updateProfile("Dr. John Smith", "Institute of Genomics", "0000-0002-1825-0097", bioCid)
+        .accounts({
+          wallet: author.publicKey,
+          profile: profilePda(author.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const profile = await program.account.researcherProfile.fetch(profilePda(author.publicKey));
+      assert.equal(profile.displayName, "Dr. John Smith");
+      assert.equal(profile.affiliation, "Institute of Genomics");
+      assert.equal(profile.orcid, "0000-0002-1825-0097");
+      assert.deepEqual(Array.from(profile.bioCid.digest), cidDigest(bioCid));
+      assert.equal(profile.papersCompleted, 1);
+    });
+
+    it("Should fail with an invalid ORCID checksum", async () => {
+      try {
+        await program.methods
+          .updateProfile("Dr. John Smith", "", "0000-0002-1825-0098", "")
+          .accounts({
+            wallet: author.publicKey,
+            profile: profilePda(author.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidOrcid");
+      }
+    });
+  });
+
+  describe("Paper Revisions", () => {
+    const revisionData = {
+      ipfsHash: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+      contentHash: Array.from(Buffer.alloc(32, 7)),
+      changelogCid: "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o",
+    };
+
+    const revisionPda = (paper: PublicKey, version: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("revision"),
+          paper.toBuffer(),
+          new anchor.BN(version).toArrayLike(Buffer, "le", 4),
+        ],
+        program.programId
+      )[0];
+
+    it("Author can publish a revision of a published paper", async () => {
+      await program.methods
+        .publishRevision(
+          revisionData.ipfsHash,
+          revisionData.contentHash,
+          revisionData.changelogCid
+        )
+        .accounts({
+          author: author.publicKey,
+          paper: paperPda,
+          revision: revisionPda(paperPda, 2),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const revision = await program.account.paperRevision.fetch(revisionPda(paperPda, 2));
+
+      assert.equal(paper.manuscriptVersion, 2);
+      assert.deepEqual(Array.from(paper.contentId.digest), cidDigest(paperData.ipfsHash)); // Version 1 stays on the paper
+      assert.equal(revision.version, 2);
+      assert.equal(revision.contentId.version, 1);
+      assert.deepEqual(Array.from(revision.contentHash), revisionData.contentHash);
+      assert.deepEqual(Array.from(revision.changelogCid.digest), cidDigest(revisionData.changelogCid));
+    });
+
+    it("Should fail when non-author publishes a revision", async () => {
+      try {
+        await program.methods
+          .publishRevision(
+            revisionData.ipfsHash,
+            revisionData.contentHash,
+            revisionData.changelogCid
+          )
+          .accounts({
+            author: funder.publicKey,
+            paper: paperPda,
+            revision: revisionPda(paperPda, 3),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail to revise a draft paper", async () => {
+      const draftPaperPda = paperPdaFor(author.publicKey, 1);
+
+      try {
+        await program.methods
+          .publishRevision(
+            revisionData.ipfsHash,
+            revisionData.contentHash,
+            revisionData.changelogCid
+          )
+          .accounts({
+            author: author.publicKey,
+            paper: draftPaperPda,
+            revision: revisionPda(draftPaperPda, 2),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("PaperNotPublished");
+      }
+    });
+  });
+
+  describe("Citations", () => {
+    const citationPdaFor = (citingPaper: PublicKey, citedPaper: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("citation"),
+          citingPaper.toBuffer(),
+          citedPaper.toBuffer(),
+        ],
+        program.programId
+      )[0];
+
+    it("Author can cite another paper on the platform", async () => {
+      // Paper 1 is authored by the funder and cites paper 0
+      await program.methods
+        .addCitation()
+        .accounts({
+          author: funder.publicKey,
+          citingPaper: paperPdaFor(funder.publicKey, 0),
+          citedPaper: paperPdaFor(author.publicKey, 0),
+          citation: citationPdaFor(paperPdaFor(funder.publicKey, 0), paperPdaFor(author.publicKey, 0)),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      const citingPaper = await program.account.researchPaper.fetch(paperPdaFor(funder.publicKey, 0));
+      const citedPaper = await program.account.researchPaper.fetch(paperPdaFor(author.publicKey, 0));
+      const citation = await program.account.citation.fetch(citationPdaFor(paperPdaFor(funder.publicKey, 0), paperPdaFor(author.publicKey, 0)));
+
+      assert.equal(citingPaper.citationsOut, 1);
+      assert.equal(citedPaper.citationsIn, 1);
+      assert.equal(citation.target.paper[0].toString(), citedPaper.id.toString());
+    });
+
+    it("Author can cite an external DOI", async () => {
+      const doi = "10.1038/nature12373";
+      const [doiCitationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("citation-doi"),
+          paperPdaFor(funder.publicKey, 0).toBuffer(),
+          createHash("sha256").update(doi).digest(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .addExternalCitation(doi)
+        .accounts({
+          author: funder.publicKey,
+          citingPaper: paperPdaFor(funder.publicKey, 0),
+          citation: doiCitationPda,
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      const citingPaper = await program.account.researchPaper.fetch(paperPdaFor(funder.publicKey, 0));
+      const citation = await program.account.citation.fetch(doiCitationPda);
+      assert.equal(citingPaper.citationsOut, 2);
+      assert.equal(citation.target.doi[0], doi);
+    });
+
+    it("Should fail to cite a paper twice", async () => {
+      try {
+        await program.methods
+          .addCitation()
+          .accounts({
+            author: funder.publicKey,
+            citingPaper: paperPdaFor(funder.publicKey, 0),
+            citedPaper: paperPdaFor(author.publicKey, 0),
+            citation: citationPdaFor(paperPdaFor(funder.publicKey, 0), paperPdaFor(author.publicKey, 0)),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+
+    it("Should fail to cite an unpublished paper", async () => {
+      try {
+        await program.methods
+          .addCitation()
+          .accounts({
+            author: author.publicKey,
+            citingPaper: paperPdaFor(author.publicKey, 0),
+            citedPaper: paperPdaFor(author.publicKey, 1),
+            citation: citationPdaFor(paperPdaFor(author.publicKey, 0), paperPdaFor(author.publicKey, 1)),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("PaperNotPublished");
+      }
+    });
+
+    it("Should fail with a malformed DOI", async () => {
+      const doi = "not-a-doi";
+      const [doiCitationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("citation-doi"),
+          paperPdaFor(funder.publicKey, 0).toBuffer(),
+          createHash("sha256").update(doi).digest(),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .addExternalCitation(doi)
+          .accounts({
+            author: funder.publicKey,
+            citingPaper: paperPdaFor(funder.publicKey, 0),
+            citation: doiCitationPda,
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCitation");
+      }
+    });
+  });
+
+  describe("Priority Commitments", () => {
+    const salt = Array.from(createHash("sha256").update("priority-salt").digest());
+
+    const commitmentFor = (committer: PublicKey, cid: string, commitSalt: number[]) =>
+      Array.from(
+        createHash("sha256")
+          .update(Buffer.concat([committer.toBuffer(), Buffer.from(cidDigest(cid)), Buffer.from(commitSalt)]))
+          .digest()
+      );
+
+    const priorityPdaFor = (committer: PublicKey, commitment: number[]) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("priority"), committer.toBuffer(), Buffer.from(commitment)],
+        program.programId
+      )[0];
+
+    it("Author can commit to a manuscript and later reveal it", async () => {
+      const commitment = commitmentFor(author.publicKey, paperData.ipfsHash, salt);
+      const priorityPda = priorityPdaFor(author.publicKey, commitment);
+
+      await program.methods
+        .commitPriority(commitment)
+        .accounts({
+          committer: author.publicKey,
+          priorityCommitment: priorityPda,
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      await program.methods
+        .revealPriority(salt)
+        .accounts({
+          committer: author.publicKey,
+          priorityCommitment: priorityPda,
+          paper: paperPda,
+        })
+        .signers([author])
+        .rpc();
+
+      const priority = await program.account.priorityCommitment.fetch(priorityPda);
+      assert.equal(priority.paper.toString(), paperPda.toString());
+      assert.isTrue(priority.revealedAt.toNumber() >= priority.committedAt.toNumber());
+    });
+
+    it("Should fail to reveal with the wrong salt", async () => {
+      const commitment = commitmentFor(author.publicKey, uniqueCid("secondPaper"), salt);
+      const priorityPda = priorityPdaFor(author.publicKey, commitment);
+
+      await program.methods
+        .commitPriority(commitment)
+        .accounts({
+          committer: author.publicKey,
+          priorityCommitment: priorityPda,
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      try {
+        await program.methods
+          .revealPriority(Array.from(Buffer.alloc(32)))
+          .accounts({
+            committer: author.publicKey,
+            priorityCommitment: priorityPda,
+            paper: paperPda,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCommitmentReveal");
+      }
+    });
+  });
+
+  describe("Admin Functions", () => {
+    it("Admin can pause the program", async () => {
+      await program.methods
+        .togglePause()
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      // Emergency pause leaves claims and refunds open
+      assert.equal(
+        programState.pauseFlags,
+        PAUSE.submissions | PAUSE.funding | PAUSE.voting | PAUSE.reviews
+      );
+    });
+
+    it("Should fail operations when paused", async () => {
+      const pausedPaperPda = paperPdaFor(author.publicKey, 2);
+
+      try {
+        await program.methods
+          .submitPaper(
+            "Paused Paper",
+            paperData.abstractText,
+            paperData.ipfsHash,
+            paperData.authors,
+            paperData.fundingGoal,
+            paperData.fundingPeriodDays,
+            paperData.category,
+            paperData.keywords
+          )
+          .accounts({
+            author: author.publicKey,
+            authorIndex: authorIndexPda(author.publicKey),
+            authorProfile: profilePda(author.publicKey),
+            paper: pausedPaperPda,
+            metadata: metadataPda(pausedPaperPda),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ProgramPaused");
+      }
+    });
+
+    it("Admin can unpause the program", async () => {
+      await program.methods
+        .togglePause()
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.pauseFlags, 0);
+    });
+
+    it("Admin can pause and unpause a single instruction family", async () => {
+      await program.methods
+        .setPauseFlags(PAUSE.voting | PAUSE.claims)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      let programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.pauseFlags, PAUSE.voting | PAUSE.claims);
+
+      await program.methods
+        .clearPauseFlags(PAUSE.voting)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.pauseFlags, PAUSE.claims);
+
+      await program.methods
+        .clearPauseFlags(PAUSE.claims)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.pauseFlags, 0);
+    });
+
+    it("Should fail with unknown pause flags", async () => {
+      try {
+        await program.methods
+          .setPauseFlags(1 << 7)
+          .accounts({
+            admin: admin.publicKey,
+            programState: programStatePda,
+          })
+          .signers([admin])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPauseFlags");
+      }
+    });
+
+    it("Should fail when non-admin tries to set pause flags", async () => {
+      try {
+        await program.methods
+          .setPauseFlags(PAUSE.funding)
+          .accounts({
+            admin: author.publicKey, // Non-admin
+            programState: programStatePda,
+          })
+          .signers([author])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail when non-admin tries to queue a settings change", async () => {
+      try {
+        await program.methods
+          .queueSettingsChange(400, new anchor.BN(3_000_000), null, null)
+          .accounts({
+            admin: author.publicKey, // Non-admin
+            programState: programStatePda,
+            settingsChange: settingsChangePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail with fee rate too high", async () => {
+      try {
+        await program.methods
+          .queueSettingsChange(1500, null, null, null) // 15% - too high
+          .accounts({
+            admin: admin.publicKey,
+            programState: programStatePda,
+            settingsChange: settingsChangePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("FeeTooHigh");
+      }
+    });
+
+    it("Should fail to queue a vote weight formula with a zero divisor", async () => {
+      try {
+        await program.methods
+          .queueSettingsChange(null, null, null, {
+            divisor: new anchor.BN(0),
+            cap: new anchor.BN(10),
+            zeroBalanceWeight: new anchor.BN(1),
+            curve: { linear: {} },
+          })
+          .accounts({
+            admin: admin.publicKey,
+            programState: programStatePda,
+            settingsChange: settingsChangePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidParameterValue");
+      }
+    });
+
+    it("Should fail to queue a zero-balance weight above the cap", async () => {
+      try {
+        await program.methods
+          .queueSettingsChange(null, null, null, {
+            divisor: new anchor.BN(1),
+            cap: new anchor.BN(10),
+            zeroBalanceWeight: new anchor.BN(11),
+            curve: { linear: {} },
+          })
+          .accounts({
+            admin: admin.publicKey,
+            programState: programStatePda,
+            settingsChange: settingsChangePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidParameterValue");
+      }
+    });
+
+    it("Admin can queue a settings change", async () => {
+      const voteWeight = {
+        divisor: new anchor.BN(2),
+        cap: new anchor.BN(5),
+        zeroBalanceWeight: new anchor.BN(0),
+        curve: { squareRoot: {} },
+      };
+
+      await program.methods
+        .queueSettingsChange(300, new anchor.BN(2_000_000), null, voteWeight) // 3% fee, 2 token minimum
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+          settingsChange: settingsChangePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      const settingsChange = await program.account.pendingSettingsChange.fetch(settingsChangePda);
+
+      // Nothing changes until the delay has elapsed
+      assert.equal(programState.platformFeeRate, 250);
+      assert.equal(programState.minFundingGoal.toNumber(), 1_000_000);
+      assert.equal(settingsChange.platformFeeRate, 300);
+      assert.equal(settingsChange.minFundingGoal.toNumber(), 2_000_000);
+      assert.equal(settingsChange.voteWeight.divisor.toNumber(), 2);
+      assert.isDefined(settingsChange.voteWeight.curve.squareRoot);
+      assert.equal(programState.voteWeightDivisor.toNumber(), 1);
+      assert.equal(
+        settingsChange.effectiveAt.toNumber(),
+        settingsChange.queuedAt.toNumber() + programState.settingsDelay.toNumber()
+      );
+    });
+
+    it("Should fail to execute a settings change before the delay", async () => {
+      try {
+        await program.methods
+          .executeSettingsChange()
+          .accounts({
+            executor: funder.publicKey,
+            programState: programStatePda,
+            settingsChange: settingsChangePda,
+            proposer: admin.publicKey,
+          })
+          .signers([funder])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("SettingsChangeNotReady");
+      }
+    });
+
+    it("Admin can cancel a queued settings change", async () => {
+      await program.methods
+        .cancelSettingsChange()
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+          settingsChange: settingsChangePda,
+          proposer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const settingsChange = await program.account.pendingSettingsChange.fetchNullable(settingsChangePda);
+      assert.isNull(settingsChange);
+    });
+  });
+
+  describe("Account Migration", () => {
+    it("Should fail to migrate program state already at the current version", async () => {
+      try {
+        await program.methods
+          .migrateProgramState()
+          .accounts({
+            admin: admin.publicKey,
+            programState: programStatePda,
+            governanceMint: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyMigrated");
+      }
+    });
+
+    it("Should fail to migrate a paper already at the current version", async () => {
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      assert.equal(paper.version, 9);
+
+      try {
+        await program.methods
+          .migrateResearchPaper()
+          .accounts({
+            authority: author.publicKey,
+            paper: paperPda,
+            metadata: metadataPda(paperPda),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyMigrated");
+      }
+    });
+
+    it("Should fail to migrate a vote already in the current layout", async () => {
+      try {
+        await program.methods
+          .migrateVote()
+          .accounts({
+            voter: voter.publicKey,
+            vote: votePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyMigrated");
+      }
+    });
+  });
+
+  describe("Admin Handover", () => {
+    let newAdmin: Keypair;
+
+    before(async () => {
+      newAdmin = Keypair.generate();
+    });
+
+    it("Admin can propose a new admin", async () => {
+      await program.methods
+        .proposeAdmin(newAdmin.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.admin.toString(), admin.publicKey.toString());
+      assert.equal(programState.pendingAdmin.toString(), newAdmin.publicKey.toString());
+    });
+
+    it("Should fail when a key other than the proposed admin accepts", async () => {
+      try {
+        await program.methods
+          .acceptAdmin()
+          .accounts({
+            newAdmin: author.publicKey,
+            programState: programStatePda,
+          })
+          .signers([author])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Admin can cancel a pending proposal", async () => {
+      await program.methods
+        .cancelAdminProposal()
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.isNull(programState.pendingAdmin);
+    });
+
+    it("Proposed admin can accept and hand back", async () => {
+      await program.methods
+        .proposeAdmin(newAdmin.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: newAdmin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([newAdmin])
+        .rpc();
+
+      let programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.admin.toString(), newAdmin.publicKey.toString());
+      assert.isNull(programState.pendingAdmin);
+
+      // Hand control back so the remaining tests keep using the original admin
+      await program.methods
+        .proposeAdmin(admin.publicKey)
+        .accounts({
+          admin: newAdmin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([newAdmin])
+        .rpc();
+
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.admin.toString(), admin.publicKey.toString());
+    });
+  });
+
+  describe("Edge Cases and Error Handling", () => {
+    it("Should handle maximum string lengths", async () => {
+      const longTitle = "A".repeat(100); // Max length
+      const longAbstract = "B".repeat(1000); // Max length
+      const maxAuthors = Array(10).fill(0).map((_, i) => `Author${i}`); // Max 10 authors
+
+      const maxLengthPaperPda = paperPdaFor(author.publicKey, 2);
+
+      await program.methods
+        .submitPaper(
+          longTitle,
+          longAbstract,
+          uniqueCid("maxLengthPaper"),
+          maxAuthors,
+          paperData.fundingGoal,
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
+          paper: maxLengthPaperPda,
+          metadata: metadataPda(maxLengthPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("maxLengthPaper")),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const metadata = await program.account.paperMetadata.fetch(metadataPda(maxLengthPaperPda));
+      assert.equal(metadata.title, longTitle);
+      assert.equal(metadata.abstractText, longAbstract);
+      assert.equal(metadata.authors.length, 10);
+    });
+
+    it("Should fail with too long strings", async () => {
+      const tooLongTitle = "A".repeat(101); // Too long
+
+      const tooLongPaperPda = paperPdaFor(author.publicKey, 3);
+
+      try {
+        await program.methods
+          .submitPaper(
+            tooLongTitle,
+            paperData.abstractText,
+            paperData.ipfsHash,
+            paperData.authors,
+            paperData.fundingGoal,
+            paperData.fundingPeriodDays,
+            paperData.category,
+            paperData.keywords
+          )
+          .accounts({
+            author: author.publicKey,
+            authorIndex: authorIndexPda(author.publicKey),
+            authorProfile: profilePda(author.publicKey),
+            paper: tooLongPaperPda,
+            metadata: metadataPda(tooLongPaperPda),
+            manuscriptRegistry: manuscriptPda(paperData.ipfsHash),
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTitle");
+      }
+    });
+
+    it("Should handle zero token balance voting", async () => {
+      const zeroBalanceVoter = Keypair.generate();
+      await provider.connection.requestAirdrop(zeroBalanceVoter.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const zeroBalanceTokenAccount = await createAccount(
+        provider.connection,
+        zeroBalanceVoter,
+        mint,
+        zeroBalanceVoter.publicKey
+      );
+
+      const [zeroBalanceVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          paperPdaFor(funder.publicKey, 0).toBuffer(), // Vote on second paper
+          zeroBalanceVoter.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .votePaper(false, new anchor.BN(0)) // Downvote without a lock
+        .accounts({
+          voter: zeroBalanceVoter.publicKey,
+          paper: paperPdaFor(funder.publicKey, 0),
+          programState: programStatePda,
+          voteEscrow: null,
+          voterCredits: null,
+          vote: zeroBalanceVotePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([zeroBalanceVoter])
+        .rpc();
+
+      const vote = await program.account.vote.fetch(zeroBalanceVotePda);
+      assert.equal(vote.weight.toNumber(), 1); // Should default to weight 1
+      assert.equal(vote.isUpvote, false);
+    });
+
+    it("Should give a lock below one token the zero-balance weight", async () => {
+      const dustVoter = Keypair.generate();
+      await provider.connection.requestAirdrop(dustVoter.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const dustTokenAccount = await createAccount(
+        provider.connection,
+        dustVoter,
+        mint,
+        dustVoter.publicKey
+      );
+      await mintTo(provider.connection, admin, mint, dustTokenAccount, admin, 1);
+
+      const [dustEscrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote-escrow"), dustVoter.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .lockTokens(new anchor.BN(1), new anchor.BN(4 * 365 * 24 * 60 * 60))
+        .accounts({
+          owner: dustVoter.publicKey,
+          programState: programStatePda,
+          voteEscrow: dustEscrowPda,
+          ownerTokenAccount: dustTokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([dustVoter])
+        .rpc();
+
+      const [dustVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          paperPdaFor(funder.publicKey, 0).toBuffer(),
+          dustVoter.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .votePaper(true, new anchor.BN(0))
+        .accounts({
+          voter: dustVoter.publicKey,
+          paper: paperPdaFor(funder.publicKey, 0),
+          programState: programStatePda,
+          voteEscrow: dustEscrowPda,
+          voterCredits: null,
+          vote: dustVotePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([dustVoter])
+        .rpc();
+
+      // One base unit is far below one token per divisor, but never weighs less than no lock
+      const vote = await program.account.vote.fetch(dustVotePda);
+      assert.equal(vote.weight.toNumber(), 1);
+    });
+
+    it("Should handle funding deadline expiration", async () => {
+      // Create a paper with very short funding period
+      const expiredPaperPda = paperPdaFor(author.publicKey, 3);
+
+      await program.methods
+        .submitPaper(
+          "Expired Paper",
+          paperData.abstractText,
+          uniqueCid("expiredPaper"),
+          paperData.authors,
+          paperData.fundingGoal,
+          new anchor.BN(1), // 1 day funding period
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
+          paper: expiredPaperPda,
+          metadata: metadataPda(expiredPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("expiredPaper")),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      await program.methods
+        .publishPaper()
+        .accounts({
+          authority: author.publicKey,
+          paper: expiredPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          programState: programStatePda,
+          ...(await categoryAccounts(0)),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      // Note: In a real test, you would need to wait for the deadline to pass
+      // or manipulate the clock. For this test, we'll just verify the paper was created
+      const paper = await program.account.researchPaper.fetch(expiredPaperPda);
+      assert.isTrue(paper.fundingDeadline > 0);
+    });
+
+    it("Should fail to expire a paper before its funding deadline", async () => {
+      try {
+        await program.methods
+          .expirePaper()
+          .accounts({
+            paper: paperPdaFor(author.publicKey, 3),
+          })
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("FundingDeadlineNotPassed");
+      }
+    });
+
+    it("Should fail to refund funding on a paper that has not expired", async () => {
+      try {
+        await program.methods
+          .refundFunding()
+          .accounts({
+            funder: funder.publicKey,
+            paper: paperPda,
+            funding: fundingPda,
+            paperTokenAccount: paperTokenAccountPda,
+            funderTokenAccount: funderTokenAccount,
+            retraction: null,
+            authorProfile: profilePda(author.publicKey),
+            programState: programStatePda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaperStatus");
+      }
+    });
+  });
+
+  describe("Integration Tests", () => {
+    it("Complete workflow: submit -> publish -> fund -> vote -> claim", async () => {
+      const workflow = {
+        title: "Complete Workflow Paper",
+        abstractText: "Testing the complete research paper workflow from submission to completion.",
+        ipfsHash: uniqueCid("workflow"),
+        authors: ["Dr. Workflow"],
+        fundingGoal: new anchor.BN(3_000_000),
+        fundingPeriodDays: new anchor.BN(60),
+      };
+
+      // Step 1: Submit paper
+      const workflowPaperPda = paperPdaFor(author.publicKey, 4);
+
+      await program.methods
+        .submitPaper(
+          workflow.title,
+          workflow.abstractText,
+          workflow.ipfsHash,
+          workflow.authors,
+          workflow.fundingGoal,
+          workflow.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
+          paper: workflowPaperPda,
+          metadata: metadataPda(workflowPaperPda),
+          manuscriptRegistry: manuscriptPda(workflow.ipfsHash),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      let paper = await program.account.researchPaper.fetch(workflowPaperPda);
+      assert.equal(paper.status.draft !== undefined, true);
+
+      // Step 2: Publish paper
+      await program.methods
+        .publishPaper()
+        .accounts({
+          authority: author.publicKey,
+          paper: workflowPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          programState: programStatePda,
+          ...(await categoryAccounts(0)),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      paper = await program.account.researchPaper.fetch(workflowPaperPda);
+      assert.equal(paper.status.published !== undefined, true);
+
+      // Step 3: Vote on paper
+      const [workflowVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          paperPdaFor(author.publicKey, 4).toBuffer(),
+          voter.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .votePaper(true, new anchor.BN(0))
+        .accounts({
+          voter: voter.publicKey,
+          paper: workflowPaperPda,
+          programState: programStatePda,
+          voteEscrow: voteEscrowPda,
+          voterCredits: null,
+          vote: workflowVotePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+      paper = await program.account.researchPaper.fetch(workflowPaperPda);
+      assert.isTrue(paper.upvotes.toNumber() > 0);
+
+      // Step 4: Fund paper to completion
+      const [workflowPaperTokenPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("paper-token"), paperPdaFor(author.publicKey, 4).toBuffer()],
+        program.programId
+      );
+
+      const [workflowFundingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("funding"),
+          paperPdaFor(author.publicKey, 4).toBuffer(),
+          funder.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      // Create paper token account
+      await createAccount(
+        provider.connection,
+        admin,
+        mint,
+        workflowPaperTokenPda,
+        admin
+      );
+
+      await program.methods
+        .fundPaper(workflow.fundingGoal)
+        .accounts({
+          funder: funder.publicKey,
+          paper: workflowPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          funderTokenAccount: funderTokenAccount,
+          paperTokenAccount: workflowPaperTokenPda,
+          platformTokenAccount: platformVaultPda,
+          funding: workflowFundingPda,
+          programState: programStatePda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      paper = await program.account.researchPaper.fetch(workflowPaperPda);
+      assert.equal(paper.status.fullyFunded !== undefined, true);
+
+      // Step 5: Claim funds
+      await program.methods
+        .claimFunds()
+        .accounts({
+          author: author.publicKey,
+          paper: workflowPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          paperTokenAccount: workflowPaperTokenPda,
+          authorTokenAccount: authorTokenAccount,
+          programState: programStatePda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      paper = await program.account.researchPaper.fetch(workflowPaperPda);
+      assert.equal(paper.status.completed !== undefined, true);
+
+      // Verify final state
+      const programState = await program.account.programState.fetch(programStatePda);
+      const authorIndex = await program.account.authorIndex.fetch(authorIndexPda(author.publicKey));
+      assert.equal(authorIndex.paperCount.toNumber(), 5); // Author's papers 0-4
+      assert.isTrue(programState.totalFunding.toNumber() > 0);
+    });
+
+    it("Multiple funders scenario", async () => {
+      const multiFundingPaper = {
+        title: "Multi-Funder Research",
+        abstractText: "Research funded by multiple contributors",
+        ipfsHash: uniqueCid("multiFunding"),
+        authors: ["Dr. Multi"],
+        fundingGoal: new anchor.BN(6_000_000), // 6 tokens
+        fundingPeriodDays: new anchor.BN(45),
+      };
+
+      // Submit and publish paper
+      const multiFundPaperPda = paperPdaFor(author.publicKey, 5);
+
+      await program.methods
+        .submitPaper(
+          multiFundingPaper.title,
+          multiFundingPaper.abstractText,
+          multiFundingPaper.ipfsHash,
+          multiFundingPaper.authors,
+          multiFundingPaper.fundingGoal,
+          multiFundingPaper.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
+          paper: multiFundPaperPda,
+          metadata: metadataPda(multiFundPaperPda),
+          manuscriptRegistry: manuscriptPda(multiFundingPaper.ipfsHash),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      await program.methods
+        .publishPaper()
+        .accounts({
+          authority: author.publicKey,
+          paper: multiFundPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          programState: programStatePda,
+          ...(await categoryAccounts(0)),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      // Create paper token account
+      const [multiFundPaperTokenPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("paper-token"), paperPdaFor(author.publicKey, 5).toBuffer()],
+        program.programId
+      );
+
+      await createAccount(
+        provider.connection,
+        admin,
+        mint,
+        multiFundPaperTokenPda,
+        admin
+      );
+
+      // First funder funds 2 tokens
+      const [firstFundingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("funding"),
+          paperPdaFor(author.publicKey, 5).toBuffer(),
+          funder.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .fundPaper(new anchor.BN(2_000_000))
+        .accounts({
+          funder: funder.publicKey,
+          paper: multiFundPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          funderTokenAccount: funderTokenAccount,
+          paperTokenAccount: multiFundPaperTokenPda,
+          platformTokenAccount: platformVaultPda,
+          funding: firstFundingPda,
+          programState: programStatePda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      // Second funder (voter) funds remaining amount
+      const [secondFundingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("funding"),
+          paperPdaFor(author.publicKey, 5).toBuffer(),
+          voter.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .fundPaper(new anchor.BN(4_500_000)) // More than needed to test excess
+        .accounts({
+          funder: voter.publicKey,
+          paper: multiFundPaperPda,
+          authorProfile: profilePda(author.publicKey),
+          funderTokenAccount: voterTokenAccount,
+          paperTokenAccount: multiFundPaperTokenPda,
+          platformTokenAccount: platformVaultPda,
+          funding: secondFundingPda,
+          programState: programStatePda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(multiFundPaperPda);
+      const firstFunding = await program.account.funding.fetch(firstFundingPda);
+      const secondFunding = await program.account.funding.fetch(secondFundingPda);
+
+      assert.equal(paper.status.fullyFunded !== undefined, true);
+      assert.isTrue(paper.fundingCurrent.toNumber() >= multiFundingPaper.fundingGoal.toNumber());
+      assert.equal(firstFunding.funder.toString(), funder.publicKey.toString());
+      assert.equal(secondFunding.funder.toString(), voter.publicKey.toString());
+    });
+  });
+
+  describe("Gas and Performance Tests", () => {
+    it("Measures transaction costs", async () => {
+      const balanceBefore = await provider.connection.getBalance(author.publicKey);
+      
+      const gasPaperPda = paperPdaFor(author.publicKey, 6);
+
+      const tx = await program.methods
+        .submitPaper(
+          "Gas Test Paper",
+          paperData.abstractText,
+          uniqueCid("gasPaper"),
+          paperData.authors,
+          paperData.fundingGoal,
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
+          paper: gasPaperPda,
+          metadata: metadataPda(gasPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("gasPaper")),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const balanceAfter = await provider.connection.getBalance(author.publicKey);
+      const txCost = balanceBefore - balanceAfter;
+      
+      console.log(`Transaction cost for submit_paper: ${txCost / LAMPORTS_PER_SOL} SOL`);
+      
+      // Verify transaction was successful
+      const metadata = await program.account.paperMetadata.fetch(metadataPda(gasPaperPda));
+      assert.equal(metadata.title, "Gas Test Paper");
+    });
+  });
+
+  describe("Event Testing", () => {
+    it("Emits events correctly", async () => {
+      const eventPaperPda = paperPdaFor(author.publicKey, 7);
+
+      // Listen for events
+      let eventReceived = false;
+      const listener = program.addEventListener("PaperSubmittedEvent", (event) => {
+        console.log("Received PaperSubmittedEvent:", event);
+        eventReceived = true;
+        assert.equal(event.title, "Event Test Paper");
+        assert.equal(event.author.toString(), author.publicKey.toString());
+      });
+
+      await program.methods
+        .submitPaper(
+          "Event Test Paper",
+          paperData.abstractText,
+          uniqueCid("eventPaper"),
+          paperData.authors,
+          paperData.fundingGoal,
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: author.publicKey,
+          authorIndex: authorIndexPda(author.publicKey),
+          authorProfile: profilePda(author.publicKey),
+          paper: eventPaperPda,
+          metadata: metadataPda(eventPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("eventPaper")),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      // Give some time for event to be processed
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      // Clean up listener
+      await program.removeEventListener(listener);
+      
+      // Note: Event testing might be flaky in test environments
+      // In production, you'd want more robust event handling
+    });
+  });
+
+  describe("Governance", () => {
+    let proposalPda: PublicKey;
+    let governanceVotePda: PublicKey;
+
+    before(async () => {
+      [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      [governanceVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("governance-vote"),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+          funder.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+    });
+
+    it("Should fail to propose a zero-balance weight above the cap", async () => {
+      const programState = await program.account.programState.fetch(programStatePda);
+
+      try {
+        await program.methods
+          .createProposal({ voteWeightZeroBalance: [programState.voteWeightCap.addn(1)] })
+          .accounts({
+            proposer: funder.publicKey,
+            programState: programStatePda,
+            proposerTokenAccount: funderTokenAccount,
+            proposal: proposalPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidParameterValue");
+      }
+    });
+
+    it("Token holder can create a proposal", async () => {
+      await program.methods
+        .createProposal({ voteWeightCap: [new anchor.BN(20)] })
+        .accounts({
+          proposer: funder.publicKey,
+          programState: programStatePda,
+          proposerTokenAccount: funderTokenAccount,
+          proposal: proposalPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      const proposal = await program.account.governanceProposal.fetch(proposalPda);
+      assert.equal(proposal.id.toNumber(), 0);
+      assert.equal(proposal.proposer.toString(), funder.publicKey.toString());
+      assert.equal(proposal.executed, false);
+    });
+
+    it("Token holder can vote by escrowing tokens", async () => {
+      const balanceBefore = await getAccount(provider.connection, funderTokenAccount);
+
+      await program.methods
+        .castGovernanceVote(new anchor.BN(0), true, new anchor.BN(1_000_000))
+        .accounts({
+          voter: funder.publicKey,
+          programState: programStatePda,
+          proposal: proposalPda,
+          voterTokenAccount: funderTokenAccount,
+          governanceVault: governanceVaultPda,
+          governanceVote: governanceVotePda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      const proposal = await program.account.governanceProposal.fetch(proposalPda);
+      const balanceAfter = await getAccount(provider.connection, funderTokenAccount);
+      assert.equal(proposal.votesFor.toNumber(), 1_000_000);
+      assert.equal(Number(balanceBefore.amount) - Number(balanceAfter.amount), 1_000_000);
+    });
+
+    it("Should fail to execute while voting is active", async () => {
+      try {
+        await program.methods
+          .executeProposal(new anchor.BN(0))
+          .accounts({
+            executor: voter.publicKey,
+            programState: programStatePda,
+            proposal: proposalPda,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ProposalVotingActive");
+      }
+    });
+
+    it("Anyone can execute a passed proposal after voting ends", async () => {
+      await sleep(6000);
+
+      await program.methods
+        .executeProposal(new anchor.BN(0))
+        .accounts({
+          executor: voter.publicKey,
+          programState: programStatePda,
+          proposal: proposalPda,
+        })
+        .signers([voter])
+        .rpc();
+
+      const proposal = await program.account.governanceProposal.fetch(proposalPda);
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(proposal.executed, true);
+      assert.equal(programState.voteWeightCap.toNumber(), 20);
+
+      // Governance skips the settings timelock; its voting period is the notice period
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      assert.isBelow(now, proposal.createdAt.toNumber() + programState.settingsDelay.toNumber());
+    });
+
+    it("Voter can withdraw escrowed tokens after voting ends", async () => {
+      const balanceBefore = await getAccount(provider.connection, funderTokenAccount);
+
+      await program.methods
+        .withdrawGovernanceVote(new anchor.BN(0))
+        .accounts({
+          voter: funder.publicKey,
+          programState: programStatePda,
+          proposal: proposalPda,
+          governanceVote: governanceVotePda,
+          voterTokenAccount: funderTokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([funder])
+        .rpc();
+
+      const balanceAfter = await getAccount(provider.connection, funderTokenAccount);
+      assert.equal(Number(balanceAfter.amount) - Number(balanceBefore.amount), 1_000_000);
+      assert.isNull(await program.account.governanceVote.fetchNullable(governanceVotePda));
+    });
+
+    it("Should fail to execute a tied proposal", async () => {
+      const { proposalCount } = await program.account.programState.fetch(programStatePda);
+      const [tiedProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const ballotPda = (wallet: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("governance-vote"),
+            proposalCount.toArrayLike(Buffer, "le", 8),
+            wallet.toBuffer(),
+          ],
+          program.programId
+        )[0];
+
+      await program.methods
+        .createProposal({ voteWeightCap: [new anchor.BN(30)] })
+        .accounts({
+          proposer: funder.publicKey,
+          programState: programStatePda,
+          proposerTokenAccount: funderTokenAccount,
+          proposal: tiedProposalPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      // Half the votes in favour is not a majority
+      const ballots: [Keypair, PublicKey, boolean][] = [
+        [funder, funderTokenAccount, true],
+        [voter, voterTokenAccount, false],
+      ];
+      for (const [wallet, tokenAccount, support] of ballots) {
+        await program.methods
+          .castGovernanceVote(proposalCount, support, new anchor.BN(1_000_000))
+          .accounts({
+            voter: wallet.publicKey,
+            programState: programStatePda,
+            proposal: tiedProposalPda,
+            voterTokenAccount: tokenAccount,
+            governanceVault: governanceVaultPda,
+            governanceVote: ballotPda(wallet.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallet])
+          .rpc();
+      }
+
+      await sleep(6000);
+
+      try {
+        await program.methods
+          .executeProposal(proposalCount)
+          .accounts({
+            executor: voter.publicKey,
+            programState: programStatePda,
+            proposal: tiedProposalPda,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ProposalNotPassed");
+      }
+
+      for (const [wallet, tokenAccount] of ballots) {
+        await program.methods
+          .withdrawGovernanceVote(proposalCount)
+          .accounts({
+            voter: wallet.publicKey,
+            programState: programStatePda,
+            proposal: tiedProposalPda,
+            governanceVote: ballotPda(wallet.publicKey),
+            voterTokenAccount: tokenAccount,
+            governanceVault: governanceVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([wallet])
+          .rpc();
+      }
+
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.voteWeightCap.toNumber(), 20);
+    });
+  });
+
+  describe("Retraction", () => {
+    const noticeCid = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n";
+
+    const retractionPdaFor = (paper: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("retraction"), paper.toBuffer()],
+        program.programId
+      )[0];
+
+    const editorPdaFor = (editor: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("editor"), editor.toBuffer()],
+        program.programId
+      )[0];
+
+    it("Should fail when an unrelated wallet retracts a paper", async () => {
+      try {
+        await program.methods
+          .retractPaper({ misconduct: {} }, noticeCid)
+          .accounts({
+            authority: funder.publicKey,
+            paper: paperPdaFor(author.publicKey, 4),
+            retraction: retractionPdaFor(paperPdaFor(author.publicKey, 4)),
+            editor: null,
+            programState: programStatePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Author can retract their own paper", async () => {
+      await program.methods
+        .retractPaper({ authorRequest: {} }, noticeCid)
+        .accounts({
+          authority: author.publicKey,
+          paper: paperPdaFor(author.publicKey, 4),
+          retraction: retractionPdaFor(paperPdaFor(author.publicKey, 4)),
+          editor: null,
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(paperPdaFor(author.publicKey, 4));
+      const retraction = await program.account.retraction.fetch(retractionPdaFor(paperPdaFor(author.publicKey, 4)));
+      assert.equal(paper.status.retracted !== undefined, true);
+      assert.equal(retraction.reason.authorRequest !== undefined, true);
+      assert.equal(retraction.previousStatus.completed !== undefined, true);
+      assert.deepEqual(Array.from(retraction.noticeCid.digest), cidDigest(noticeCid));
+    });
+
+    it("Editor can retract a paper with a reason", async () => {
+      await program.methods
+        .addEditor(voter.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          editorAccount: editorPdaFor(voter.publicKey),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await program.methods
+        .retractPaper({ dataError: {} }, noticeCid)
+        .accounts({
+          authority: voter.publicKey,
+          paper: paperPdaFor(author.publicKey, 3),
+          retraction: retractionPdaFor(paperPdaFor(author.publicKey, 3)),
+          editor: editorPdaFor(voter.publicKey),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+      const retraction = await program.account.retraction.fetch(retractionPdaFor(paperPdaFor(author.publicKey, 3)));
+      assert.equal(retraction.retractedBy.toString(), voter.publicKey.toString());
+      assert.equal(retraction.reason.dataError !== undefined, true);
+    });
+
+    it("Should fail to vote on a retracted paper", async () => {
+      const [retractedVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          paperPdaFor(author.publicKey, 3).toBuffer(),
+          voter.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .votePaper(true, new anchor.BN(0))
+          .accounts({
+            voter: voter.publicKey,
+            paper: paperPdaFor(author.publicKey, 3),
+            programState: programStatePda,
+            voteEscrow: voteEscrowPda,
+            voterCredits: null,
+            vote: retractedVotePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("PaperRetracted");
+      }
+    });
+
+    it("Admin can remove an editor", async () => {
+      await program.methods
+        .removeEditor(voter.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          editorAccount: editorPdaFor(voter.publicKey),
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.isNull(await program.account.editor.fetchNullable(editorPdaFor(voter.publicKey)));
+    });
+  });
+
+  describe("Review Workflow", () => {
+    const submitDraft = async (nonce: number, label: string) => {
+      const draftPda = paperPdaFor(voter.publicKey, nonce);
+      await program.methods
+        .submitPaper(
+          paperData.title,
+          paperData.abstractText,
+          uniqueCid(label),
+          paperData.authors,
+          paperData.fundingGoal,
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: voter.publicKey,
+          authorIndex: authorIndexPda(voter.publicKey),
+          authorProfile: profilePda(voter.publicKey),
+          paper: draftPda,
+          metadata: metadataPda(draftPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid(label)),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+      return draftPda;
+    };
+
+    const reviewAccounts = (authority: PublicKey, paper: PublicKey) => ({
+      authority,
+      paper,
+      programState: programStatePda,
+    });
+
+    it("Author can request review of a draft", async () => {
+      const draftPda = await submitDraft(0, "reviewedPaper");
+
+      await program.methods
+        .requestReview()
+        .accounts(reviewAccounts(voter.publicKey, draftPda))
+        .signers([voter])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(draftPda);
+      assert.equal(paper.status.underReview !== undefined, true);
+    });
+
+    it("Should fail when the author publishes a paper under review", async () => {
+      try {
+        await program.methods
+          .publishPaper()
+          .accounts({
+            authority: voter.publicKey,
+            paper: paperPdaFor(voter.publicKey, 0),
+            authorProfile: profilePda(voter.publicKey),
+            programState: programStatePda,
+            ...(await categoryAccounts(0)),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail to reject a paper while reviews are paused", async () => {
+      await program.methods
+        .setPauseFlags(PAUSE.reviews)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .rejectPaper()
+          .accounts(reviewAccounts(admin.publicKey, paperPdaFor(voter.publicKey, 0)))
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ProgramPaused");
+      }
+
+      await program.methods
+        .clearPauseFlags(PAUSE.reviews)
+        .accounts({
+          admin: admin.publicKey,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("Admin can reject a paper under review", async () => {
+      await program.methods
+        .rejectPaper()
+        .accounts(reviewAccounts(admin.publicKey, paperPdaFor(voter.publicKey, 0)))
+        .signers([admin])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(paperPdaFor(voter.publicKey, 0));
+      assert.equal(paper.status.rejected !== undefined, true);
+    });
+
+    it("Should fail to cancel a rejected paper", async () => {
+      try {
+        await program.methods
+          .cancelPaper()
+          .accounts(reviewAccounts(voter.publicKey, paperPdaFor(voter.publicKey, 0)))
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTransition");
+      }
+    });
+
+    it("Author can cancel an unpublished draft", async () => {
+      const draftPda = await submitDraft(1, "cancelledPaper");
+
+      await program.methods
+        .cancelPaper()
+        .accounts(reviewAccounts(voter.publicKey, draftPda))
+        .signers([voter])
+        .rpc();
+
+      const paper = await program.account.researchPaper.fetch(draftPda);
+      assert.equal(paper.status.cancelled !== undefined, true);
+    });
+  });
+
+  describe("Voting Windows", () => {
+    let windowPaperPda: PublicKey;
+    let windowVotePda: PublicKey;
+    let voteTallyPda: PublicKey;
+
+    const finalizeVotes = () =>
+      program.methods
+        .finalizeVotes()
+        .accounts({
+          payer: funder.publicKey,
+          paper: windowPaperPda,
+          voteTally: voteTallyPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+    before(async () => {
+      windowPaperPda = paperPdaFor(voter.publicKey, 2);
+      [windowVotePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), windowPaperPda.toBuffer(), funder.publicKey.toBuffer()],
+        program.programId
+      );
+      [voteTallyPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote-tally"), windowPaperPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .submitPaper(
+          paperData.title,
+          paperData.abstractText,
+          uniqueCid("windowPaper"),
+          paperData.authors,
+          paperData.fundingGoal,
+          paperData.fundingPeriodDays,
+          paperData.category,
+          paperData.keywords
+        )
+        .accounts({
+          author: voter.publicKey,
+          authorIndex: authorIndexPda(voter.publicKey),
+          authorProfile: profilePda(voter.publicKey),
+          paper: windowPaperPda,
+          metadata: metadataPda(windowPaperPda),
+          manuscriptRegistry: manuscriptPda(uniqueCid("windowPaper")),
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+
+      await program.methods
+        .publishPaper()
+        .accounts({
+          authority: voter.publicKey,
+          paper: windowPaperPda,
+          authorProfile: profilePda(voter.publicKey),
+          programState: programStatePda,
+          ...(await categoryAccounts(0)),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+    });
+
+    it("Should fail when a non-admin sets a voting window", async () => {
+      const paper = await program.account.researchPaper.fetch(windowPaperPda);
+
+      try {
+        await program.methods
+          .setVotingWindow(paper.votingOpensAt, paper.votingOpensAt.addn(1))
+          .accounts({
+            admin: voter.publicKey,
+            paper: windowPaperPda,
+            programState: programStatePda,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail to extend a voting window past a voter's lock", async () => {
+      // The first paper's votes were cast with vote-escrowed BIOX
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const escrow = await program.account.voteEscrow.fetch(voteEscrowPda);
+      assert.isAbove(paper.earliestUnlockAt.toNumber(), 0);
+      assert.isAtMost(paper.earliestUnlockAt.toNumber(), escrow.unlockAt.toNumber());
+
+      try {
+        await program.methods
+          .setVotingWindow(paper.votingOpensAt, paper.earliestUnlockAt.addn(1))
+          .accounts({
+            admin: admin.publicKey,
+            paper: paperPda,
+            programState: programStatePda,
+          })
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("LockTooShort");
+      }
+    });
+
+    it("Admin can shorten a paper's voting window", async () => {
+      const paper = await program.account.researchPaper.fetch(windowPaperPda);
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      const closesAt = new anchor.BN(now + 2);
+
+      await program.methods
+        .setVotingWindow(paper.votingOpensAt, closesAt)
+        .accounts({
+          admin: admin.publicKey,
+          paper: windowPaperPda,
+          programState: programStatePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      const updated = await program.account.researchPaper.fetch(windowPaperPda);
+      assert.equal(updated.votingClosesAt.toNumber(), closesAt.toNumber());
+    });
+
+    it("Should fail to finalize votes while voting is open", async () => {
+      try {
+        await finalizeVotes();
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("VotingNotClosed");
+      }
+    });
+
+    it("Should fail to vote after the window closes", async () => {
+      await sleep(4000);
+
+      try {
+        await program.methods
+          .votePaper(true, new anchor.BN(0))
+          .accounts({
+            voter: funder.publicKey,
+            paper: windowPaperPda,
+            programState: programStatePda,
+            voteEscrow: null,
+            voterCredits: null,
+            vote: windowVotePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("VotingClosed");
+      }
+    });
+
+    it("Anyone can finalize the tally once voting closes", async () => {
+      await finalizeVotes();
+
+      const paper = await program.account.researchPaper.fetch(windowPaperPda);
+      const tally = await program.account.voteTally.fetch(voteTallyPda);
+      assert.equal(tally.paper.toString(), windowPaperPda.toString());
+      assert.equal(tally.upvotes.toNumber(), paper.upvotes.toNumber());
+      assert.equal(tally.downvotes.toNumber(), paper.downvotes.toNumber());
+      assert.equal(tally.votingClosesAt.toNumber(), paper.votingClosesAt.toNumber());
+    });
+
+    it("Should fail to finalize a tally twice", async () => {
+      try {
+        await finalizeVotes();
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+  });
+
+  describe("Account Closure", () => {
+    it("Should fail to close funding on a paper that is not settled", async () => {
+      const [openFundingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("funding"),
+          paperPdaFor(author.publicKey, 5).toBuffer(),
+          funder.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .closeFunding()
+          .accounts({
+            funder: funder.publicKey,
+            paper: paperPdaFor(author.publicKey, 5),
+            funding: openFundingPda,
+            retraction: null,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaperStatus");
+      }
+    });
+
+    it("Funders are refunded from a retracted paper before it closes", async () => {
+      const retractedPaperPda = paperPdaFor(author.publicKey, 5);
+      const [retractedPaperTokenPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("paper-token"), retractedPaperPda.toBuffer()],
+        program.programId
+      );
+      const [retractionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("retraction"), retractedPaperPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .retractPaper({ authorRequest: {} }, "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n")
+        .accounts({
+          authority: author.publicKey,
+          paper: retractedPaperPda,
+          retraction: retractionPda,
+          editor: null,
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      for (const [wallet, tokenAccount] of [
+        [funder, funderTokenAccount],
+        [voter, voterTokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [retractedFundingPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("funding"), retractedPaperPda.toBuffer(), wallet.publicKey.toBuffer()],
+          program.programId
+        );
+
+        await program.methods
+          .refundFunding()
+          .accounts({
+            funder: wallet.publicKey,
+            paper: retractedPaperPda,
+            funding: retractedFundingPda,
+            paperTokenAccount: retractedPaperTokenPda,
+            funderTokenAccount: tokenAccount,
+            retraction: retractionPda,
+            authorProfile: profilePda(author.publicKey),
+            programState: programStatePda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallet])
+          .rpc();
+
+        assert.isNull(await program.account.funding.fetchNullable(retractedFundingPda));
+      }
+
+      const escrow = await getAccount(provider.connection, retractedPaperTokenPda);
+      assert.equal(Number(escrow.amount), 0);
+
+      await program.methods
+        .closePaper()
+        .accounts({
+          author: author.publicKey,
+          paper: retractedPaperPda,
+          metadata: metadataPda(retractedPaperPda),
+          retraction: retractionPda,
+        })
+        .signers([author])
+        .rpc();
+
+      assert.isNull(await program.account.researchPaper.fetchNullable(retractedPaperPda));
+    });
+
+    it("A paper retracted after its funds were claimed closes without refunds", async () => {
+      // The integration workflow paper was completed and claimed before it was retracted
+      const claimedPaperPda = paperPdaFor(author.publicKey, 4);
+      const [claimedPaperTokenPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("paper-token"), claimedPaperPda.toBuffer()],
+        program.programId
+      );
+      const [retractionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("retraction"), claimedPaperPda.toBuffer()],
+        program.programId
+      );
+      const [claimedFundingPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("funding"), claimedPaperPda.toBuffer(), funder.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .refundFunding()
+          .accounts({
+            funder: funder.publicKey,
+            paper: claimedPaperPda,
+            funding: claimedFundingPda,
+            paperTokenAccount: claimedPaperTokenPda,
+            funderTokenAccount: funderTokenAccount,
+            retraction: retractionPda,
+            authorProfile: profilePda(author.publicKey),
+            programState: programStatePda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("FundsAlreadyClaimed");
+      }
+
+      try {
+        await program.methods
+          .closeFunding()
+          .accounts({
+            funder: funder.publicKey,
+            paper: claimedPaperPda,
+            funding: claimedFundingPda,
+            retraction: null,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("RetractionRequired");
+      }
+
+      await program.methods
+        .closeFunding()
+        .accounts({
+          funder: funder.publicKey,
+          paper: claimedPaperPda,
+          funding: claimedFundingPda,
+          retraction: retractionPda,
+        })
+        .signers([funder])
+        .rpc();
+      assert.isNull(await program.account.funding.fetchNullable(claimedFundingPda));
+
+      await program.methods
+        .closePaper()
+        .accounts({
+          author: author.publicKey,
+          paper: claimedPaperPda,
+          metadata: metadataPda(claimedPaperPda),
+          retraction: retractionPda,
+        })
+        .signers([author])
+        .rpc();
+      assert.isNull(await program.account.researchPaper.fetchNullable(claimedPaperPda));
+    });
+
+    it("Funder can close funding on a completed paper", async () => {
+      const balanceBefore = await provider.connection.getBalance(funder.publicKey);
+
+      await program.methods
+        .closeFunding()
+        .accounts({
+          funder: funder.publicKey,
+          paper: paperPda,
+          funding: fundingPda,
+          retraction: null,
+        })
+        .signers([funder])
+        .rpc();
+
+      assert.isNull(await program.account.funding.fetchNullable(fundingPda));
+      assert.isAbove(await provider.connection.getBalance(funder.publicKey), balanceBefore);
+    });
+
+    it("Should fail to vote on a completed paper", async () => {
+      const [completedVotePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), paperPda.toBuffer(), funder.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .votePaper(true, new anchor.BN(0))
+          .accounts({
+            voter: funder.publicKey,
+            paper: paperPda,
+            programState: programStatePda,
+            voteEscrow: null,
+            voterCredits: null,
+            vote: completedVotePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([funder])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaperStatus");
+      }
+    });
+
+    it("Should fail to change a vote on a completed paper", async () => {
+      try {
+        await program.methods
+          .changeVote(false)
+          .accounts({
+            voter: voter.publicKey,
+            paper: paperPda,
+            programState: programStatePda,
+            voteEscrow: voteEscrowPda,
+            vote: votePda,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaperStatus");
+      }
+    });
+
+    it("Should fail to retract a vote on a completed paper", async () => {
+      try {
+        await program.methods
+          .retractVote()
+          .accounts({
+            voter: voter.publicKey,
+            paper: paperPda,
+            programState: programStatePda,
+            vote: votePda,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaperStatus");
+      }
+    });
+
+    it("Should fail to close a vote while voting is open", async () => {
+      try {
+        await program.methods
+          .closeVote()
+          .accounts({
+            voter: voter.publicKey,
+            paper: paperPda,
+            vote: votePda,
+          })
+          .signers([voter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("VotingNotClosed");
+      }
+    });
+
+    it("Voter can close a vote on a completed paper", async () => {
+      // Voting must close first, or the vote's weight would leave a live tally
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+
+      await program.methods
+        .setVotingWindow(paper.votingOpensAt, new anchor.BN(now + 2))
         .accounts({
-          funder: funder.publicKey,
-          paper: workflowPaperPda,
-          funderTokenAccount: funderTokenAccount,
-          paperTokenAccount: workflowPaperTokenPda,
-          platformTokenAccount: platformVaultPda,
-          funding: workflowFundingPda,
+          admin: admin.publicKey,
+          paper: paperPda,
           programState: programStatePda,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
         })
-        .signers([funder])
+        .signers([admin])
         .rpc();
+      await sleep(4000);
 
-      paper = await program.account.researchPaper.fetch(workflowPaperPda);
-      assert.equal(paper.status.fullyFunded !== undefined, true);
-
-      // Step 5: Claim funds
       await program.methods
-        .claimFunds(new anchor.BN(6))
+        .closeVote()
         .accounts({
-          author: author.publicKey,
-          paper: workflowPaperPda,
-          paperTokenAccount: workflowPaperTokenPda,
-          authorTokenAccount: authorTokenAccount,
-          programState: programStatePda,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          voter: voter.publicKey,
+          paper: paperPda,
+          vote: votePda,
         })
-        .signers([author])
+        .signers([voter])
         .rpc();
 
-      paper = await program.account.researchPaper.fetch(workflowPaperPda);
-      assert.equal(paper.status.completed !== undefined, true);
-
-      // Verify final state
-      const programState = await program.account.programState.fetch(programStatePda);
-      assert.equal(programState.paperCount.toNumber(), 7); // 0-6 = 7 papers total
-      assert.isTrue(programState.totalFunding.toNumber() > 0);
+      assert.isNull(await program.account.vote.fetchNullable(votePda));
     });
 
-    it("Multiple funders scenario", async () => {
-      const multiFundingPaper = {
-        title: "Multi-Funder Research",
-        abstractText: "Research funded by multiple contributors",
-        ipfsHash: "QmMultiFundHash",
-        authors: ["Dr. Multi"],
-        fundingGoal: new anchor.BN(6_000_000), // 6 tokens
-        fundingPeriodDays: new anchor.BN(45),
-      };
+    it("Should fail when a non-author closes a paper", async () => {
+      try {
+        await program.methods
+          .closePaper()
+          .accounts({
+            author: funder.publicKey,
+            paper: paperPda,
+            metadata: metadataPda(paperPda),
+            retraction: null,
+          })
+          .signers([funder])
+          .rpc();
 
-      // Submit and publish paper
-      const [multiFundPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(7).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
 
+    it("Author can close a completed paper", async () => {
       await program.methods
-        .submitPaper(
-          multiFundingPaper.title,
-          multiFundingPaper.abstractText,
-          multiFundingPaper.ipfsHash,
-          multiFundingPaper.authors,
-          multiFundingPaper.fundingGoal,
-          multiFundingPaper.fundingPeriodDays
-        )
+        .closePaper()
         .accounts({
           author: author.publicKey,
-          paper: multiFundPaperPda,
-          programState: programStatePda,
-          systemProgram: SystemProgram.programId,
+          paper: paperPda,
+          metadata: metadataPda(paperPda),
+          retraction: null,
         })
         .signers([author])
         .rpc();
 
-      await program.methods
-        .publishPaper(new anchor.BN(7))
-        .accounts({
-          authority: author.publicKey,
-          paper: multiFundPaperPda,
-          programState: programStatePda,
-        })
-        .signers([author])
-        .rpc();
+      assert.isNull(await program.account.researchPaper.fetchNullable(paperPda));
+      assert.isNull(await program.account.paperMetadata.fetchNullable(metadataPda(paperPda)));
+    });
+  });
 
-      // Create paper token account
-      const [multiFundPaperTokenPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper-token"), new anchor.BN(7).toArrayLike(Buffer, "le", 8)],
+  // Runs last: it leaves the program in quadratic voting mode
+  describe("Quadratic Voting", () => {
+    const quadraticVoter = Keypair.generate();
+    let quadraticPaperPda: PublicKey;
+    let quadraticVotePda: PublicKey;
+    let quadraticEscrowPda: PublicKey;
+
+    // Credits are tracked per epoch, derived from the cluster clock
+    const voterCreditsPda = async (wallet = quadraticVoter.publicKey) => {
+      const programState = await program.account.programState.fetch(programStatePda);
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      const epoch = Math.floor(now / programState.voteEpochDuration.toNumber());
+      return PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote-credits"),
+          wallet.toBuffer(),
+          new anchor.BN(epoch).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+    };
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(quadraticVoter.publicKey, LAMPORTS_PER_SOL)
+      );
+
+      quadraticPaperPda = paperPdaFor(funder.publicKey, 0);
+      [quadraticVotePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), quadraticPaperPda.toBuffer(), quadraticVoter.publicKey.toBuffer()],
+        program.programId
+      );
+      [quadraticEscrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote-escrow"), quadraticVoter.publicKey.toBuffer()],
         program.programId
       );
 
-      await createAccount(
+      // Credits come from escrowed BIOX: 10 tokens buy weight 9, so 81 credits per epoch
+      const tokenAccount = await createAccount(
         provider.connection,
-        admin,
+        quadraticVoter,
         mint,
-        multiFundPaperTokenPda,
-        admin
+        quadraticVoter.publicKey
       );
+      await mintTo(provider.connection, admin, mint, tokenAccount, admin, 10_000_000);
 
-      // First funder funds 2 tokens
-      const [firstFundingPda] = PublicKey.findProgramAddressSync(
+      await program.methods
+        .lockTokens(new anchor.BN(10_000_000), new anchor.BN(4 * 365 * 24 * 60 * 60))
+        .accounts({
+          owner: quadraticVoter.publicKey,
+          programState: programStatePda,
+          voteEscrow: quadraticEscrowPda,
+          ownerTokenAccount: tokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([quadraticVoter])
+        .rpc();
+    });
+
+    it("Governance can switch to quadratic voting", async () => {
+      const { proposalCount } = await program.account.programState.fetch(programStatePda);
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [governanceVotePda] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("funding"),
-          new anchor.BN(7).toArrayLike(Buffer, "le", 8),
+          Buffer.from("governance-vote"),
+          proposalCount.toArrayLike(Buffer, "le", 8),
           funder.publicKey.toBuffer(),
         ],
         program.programId
       );
 
       await program.methods
-        .fundPaper(new anchor.BN(7), new anchor.BN(2_000_000))
+        .createProposal({ votingMode: [{ quadratic: {} }] })
         .accounts({
-          funder: funder.publicKey,
-          paper: multiFundPaperPda,
-          funderTokenAccount: funderTokenAccount,
-          paperTokenAccount: multiFundPaperTokenPda,
-          platformTokenAccount: platformVaultPda,
-          funding: firstFundingPda,
+          proposer: funder.publicKey,
           programState: programStatePda,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          proposerTokenAccount: funderTokenAccount,
+          proposal: proposalPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([funder])
         .rpc();
 
-      // Second funder (voter) funds remaining amount
-      const [secondFundingPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("funding"),
-          new anchor.BN(7).toArrayLike(Buffer, "le", 8),
-          voter.publicKey.toBuffer(),
-        ],
-        program.programId
-      );
-
       await program.methods
-        .fundPaper(new anchor.BN(7), new anchor.BN(4_500_000)) // More than needed to test excess
+        .castGovernanceVote(proposalCount, true, new anchor.BN(1_000_000))
         .accounts({
-          funder: voter.publicKey,
-          paper: multiFundPaperPda,
-          funderTokenAccount: voterTokenAccount,
-          paperTokenAccount: multiFundPaperTokenPda,
-          platformTokenAccount: platformVaultPda,
-          funding: secondFundingPda,
+          voter: funder.publicKey,
           programState: programStatePda,
+          proposal: proposalPda,
+          voterTokenAccount: funderTokenAccount,
+          governanceVault: governanceVaultPda,
+          governanceVote: governanceVotePda,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
+        .signers([funder])
+        .rpc();
+
+      await sleep(6000);
+
+      await program.methods
+        .executeProposal(proposalCount)
+        .accounts({
+          executor: voter.publicKey,
+          programState: programStatePda,
+          proposal: proposalPda,
+        })
         .signers([voter])
         .rpc();
 
-      const paper = await program.account.researchPaper.fetch(multiFundPaperPda);
-      const firstFunding = await program.account.funding.fetch(firstFundingPda);
-      const secondFunding = await program.account.funding.fetch(secondFundingPda);
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.isDefined(programState.votingMode.quadratic);
+      assert.equal(programState.voteCreditsPerEpoch.toNumber(), 100);
+    });
 
-      assert.equal(paper.status.fullyFunded !== undefined, true);
-      assert.isTrue(paper.fundingCurrent.toNumber() >= multiFundingPaper.fundingGoal.toNumber());
-      assert.equal(firstFunding.funder.toString(), funder.publicKey.toString());
-      assert.equal(secondFunding.funder.toString(), voter.publicKey.toString());
+    it("Should fail to spend credits without escrowed BIOX", async () => {
+      try {
+        await program.methods
+          .votePaper(true, new anchor.BN(1))
+          .accounts({
+            voter: quadraticVoter.publicKey,
+            paper: quadraticPaperPda,
+            programState: programStatePda,
+            voteEscrow: null,
+            voterCredits: await voterCreditsPda(),
+            vote: quadraticVotePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([quadraticVoter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientVoteCredits");
+      }
     });
-  });
 
-  describe("Gas and Performance Tests", () => {
-    it("Measures transaction costs", async () => {
-      const balanceBefore = await provider.connection.getBalance(author.publicKey);
-      
-      const [gasPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(8).toArrayLike(Buffer, "le", 8)],
-        program.programId
-      );
+    it("Should fail to spend more credits than the lock allows", async () => {
+      try {
+        await program.methods
+          .votePaper(true, new anchor.BN(82))
+          .accounts({
+            voter: quadraticVoter.publicKey,
+            paper: quadraticPaperPda,
+            programState: programStatePda,
+            voteEscrow: quadraticEscrowPda,
+            voterCredits: await voterCreditsPda(),
+            vote: quadraticVotePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([quadraticVoter])
+          .rpc();
 
-      const tx = await program.methods
-        .submitPaper(
-          "Gas Test Paper",
-          paperData.abstractText,
-          paperData.ipfsHash,
-          paperData.authors,
-          paperData.fundingGoal,
-          paperData.fundingPeriodDays
-        )
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientVoteCredits");
+      }
+    });
+
+    it("Vote weight is the square root of credits spent", async () => {
+      const before = await program.account.researchPaper.fetch(quadraticPaperPda);
+      const voterCredits = await voterCreditsPda();
+
+      await program.methods
+        .votePaper(true, new anchor.BN(16))
         .accounts({
-          author: author.publicKey,
-          paper: gasPaperPda,
+          voter: quadraticVoter.publicKey,
+          paper: quadraticPaperPda,
           programState: programStatePda,
+          voteEscrow: quadraticEscrowPda,
+          voterCredits,
+          vote: quadraticVotePda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([author])
+        .signers([quadraticVoter])
         .rpc();
 
-      const balanceAfter = await provider.connection.getBalance(author.publicKey);
-      const txCost = balanceBefore - balanceAfter;
-      
-      console.log(`Transaction cost for submit_paper: ${txCost / LAMPORTS_PER_SOL} SOL`);
-      
-      // Verify transaction was successful
-      const paper = await program.account.researchPaper.fetch(gasPaperPda);
-      assert.equal(paper.title, "Gas Test Paper");
+      const paper = await program.account.researchPaper.fetch(quadraticPaperPda);
+      const vote = await program.account.vote.fetch(quadraticVotePda);
+      const credits = await program.account.voterCredits.fetch(voterCredits);
+      assert.equal(vote.weight.toNumber(), 4);
+      assert.equal(vote.credits.toNumber(), 16);
+      assert.equal(paper.upvotes.toNumber(), before.upvotes.toNumber() + 4);
+      assert.equal(credits.spent.toNumber(), 16);
     });
-  });
 
-  describe("Event Testing", () => {
-    it("Emits events correctly", async () => {
-      const [eventPaperPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("paper"), new anchor.BN(9).toArrayLike(Buffer, "le", 8)],
-        program.programId
+    it("Should fail to spend credits backed only by a dust lock", async () => {
+      const dustVoter = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(dustVoter.publicKey, LAMPORTS_PER_SOL)
       );
 
-      // Listen for events
-      let eventReceived = false;
-      const listener = program.addEventListener("PaperSubmittedEvent", (event) => {
-        console.log("Received PaperSubmittedEvent:", event);
-        eventReceived = true;
-        assert.equal(event.title, "Event Test Paper");
-        assert.equal(event.author.toString(), author.publicKey.toString());
-      });
+      const dustTokenAccount = await createAccount(
+        provider.connection,
+        dustVoter,
+        mint,
+        dustVoter.publicKey
+      );
+      await mintTo(provider.connection, admin, mint, dustTokenAccount, admin, 1);
 
+      const [dustEscrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote-escrow"), dustVoter.publicKey.toBuffer()],
+        program.programId
+      );
       await program.methods
-        .submitPaper(
-          "Event Test Paper",
-          paperData.abstractText,
-          paperData.ipfsHash,
-          paperData.authors,
-          paperData.fundingGoal,
-          paperData.fundingPeriodDays
-        )
+        .lockTokens(new anchor.BN(1), new anchor.BN(4 * 365 * 24 * 60 * 60))
         .accounts({
-          author: author.publicKey,
-          paper: eventPaperPda,
+          owner: dustVoter.publicKey,
           programState: programStatePda,
+          voteEscrow: dustEscrowPda,
+          ownerTokenAccount: dustTokenAccount,
+          governanceVault: governanceVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([author])
+        .signers([dustVoter])
         .rpc();
 
-      // Give some time for event to be processed
-      await new Promise(resolve => setTimeout(resolve, 1000));
+      const [dustVotePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), quadraticPaperPda.toBuffer(), dustVoter.publicKey.toBuffer()],
+        program.programId
+      );
 
-      // Clean up listener
-      await program.removeEventListener(listener);
-      
-      // Note: Event testing might be flaky in test environments
-      // In production, you'd want more robust event handling
+      // The lock earns no weight of its own; the zero-balance floor doesn't buy credits
+      try {
+        await program.methods
+          .votePaper(true, new anchor.BN(1))
+          .accounts({
+            voter: dustVoter.publicKey,
+            paper: quadraticPaperPda,
+            programState: programStatePda,
+            voteEscrow: dustEscrowPda,
+            voterCredits: await voterCreditsPda(dustVoter.publicKey),
+            vote: dustVotePda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([dustVoter])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientVoteCredits");
+      }
     });
   });
 });
@@ -0,0 +1,265 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BioxResearch } from "../target/types/biox_research";
+import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ACCOUNT_SIZE,
+  AccountLayout,
+  AccountState,
+  MINT_SIZE,
+  MintLayout,
+  getAccount,
+} from "@solana/spl-token";
+import { BankrunProvider, startAnchor } from "anchor-bankrun";
+import { Clock, ProgramTestContext } from "solana-bankrun";
+import { assert, expect } from "chai";
+import { createHash } from "crypto";
+
+// Funding deadlines are whole days, so expiry is tested on an in-process bank whose clock can
+// be warped past them
+describe("biox_research expiry", () => {
+  const DAY = 24 * 60 * 60;
+  const PAUSE_REFUNDS = 1 << 5;
+
+  let context: ProgramTestContext;
+  let provider: BankrunProvider;
+  let program: Program<BioxResearch>;
+
+  const author = Keypair.generate();
+  const funder = Keypair.generate();
+  const mint = Keypair.generate().publicKey;
+  const funderTokenAccount = Keypair.generate().publicKey;
+  const digest = createHash("sha256").update("expiringPaper").digest();
+  const cid = "f01551220" + digest.toString("hex"); // Raw sha2-256 CIDv1
+
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  let programStatePda: PublicKey;
+  let paperPda: PublicKey;
+  let paperTokenPda: PublicKey;
+  let fundingPda: PublicKey;
+
+  const setTokenAccount = (address: PublicKey, owner: PublicKey, amount: bigint) => {
+    const data = Buffer.alloc(ACCOUNT_SIZE);
+    AccountLayout.encode(
+      {
+        mint,
+        owner,
+        amount,
+        delegateOption: 0,
+        delegate: PublicKey.default,
+        state: AccountState.Initialized,
+        isNativeOption: 0,
+        isNative: BigInt(0),
+        delegatedAmount: BigInt(0),
+        closeAuthorityOption: 0,
+        closeAuthority: PublicKey.default,
+      },
+      data
+    );
+    context.setAccount(address, {
+      lamports: LAMPORTS_PER_SOL,
+      data,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+    });
+  };
+
+  const refundAccounts = () => ({
+    funder: funder.publicKey,
+    paper: paperPda,
+    funding: fundingPda,
+    paperTokenAccount: paperTokenPda,
+    funderTokenAccount,
+    retraction: null,
+    authorProfile: pda(Buffer.from("researcher"), author.publicKey.toBuffer()),
+    programState: programStatePda,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+  });
+
+  before(async () => {
+    context = await startAnchor("", [], []);
+    provider = new BankrunProvider(context);
+    program = new Program<BioxResearch>(anchor.workspace.BioxResearch.idl, provider);
+
+    programStatePda = pda(Buffer.from("program-state"));
+    paperPda = pda(
+      Buffer.from("paper"),
+      author.publicKey.toBuffer(),
+      new anchor.BN(0).toArrayLike(Buffer, "le", 8)
+    );
+    paperTokenPda = pda(Buffer.from("paper-token"), paperPda.toBuffer());
+    fundingPda = pda(Buffer.from("funding"), paperPda.toBuffer(), funder.publicKey.toBuffer());
+
+    for (const wallet of [author, funder]) {
+      context.setAccount(wallet.publicKey, {
+        lamports: 10 * LAMPORTS_PER_SOL,
+        data: Buffer.alloc(0),
+        owner: SystemProgram.programId,
+        executable: false,
+      });
+    }
+
+    const mintData = Buffer.alloc(MINT_SIZE);
+    MintLayout.encode(
+      {
+        mintAuthorityOption: 1,
+        mintAuthority: provider.wallet.publicKey,
+        supply: BigInt(10_000_000),
+        decimals: 6,
+        isInitialized: true,
+        freezeAuthorityOption: 0,
+        freezeAuthority: PublicKey.default,
+      },
+      mintData
+    );
+    context.setAccount(mint, {
+      lamports: LAMPORTS_PER_SOL,
+      data: mintData,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+    });
+
+    // The escrow signs for itself, so it is its own token authority
+    setTokenAccount(funderTokenAccount, funder.publicKey, BigInt(10_000_000));
+    setTokenAccount(paperTokenPda, paperTokenPda, BigInt(0));
+    const platformVaultPda = pda(Buffer.from("platform-vault"));
+    setTokenAccount(platformVaultPda, platformVaultPda, BigInt(0));
+
+    await program.methods
+      .initialize()
+      .accounts({
+        admin: provider.wallet.publicKey,
+        programState: programStatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .submitPaper(
+        "Expiring Paper",
+        "A paper whose funding round runs out",
+        cid,
+        ["Dr. Deadline"],
+        new anchor.BN(5_000_000),
+        new anchor.BN(1), // 1 day funding period
+        { genomics: {} },
+        ["expiry"]
+      )
+      .accounts({
+        author: author.publicKey,
+        authorIndex: pda(Buffer.from("author-index"), author.publicKey.toBuffer()),
+        authorProfile: pda(Buffer.from("researcher"), author.publicKey.toBuffer()),
+        paper: paperPda,
+        metadata: pda(Buffer.from("paper-metadata"), paperPda.toBuffer()),
+        manuscriptRegistry: pda(Buffer.from("manuscript"), digest),
+        programState: programStatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([author])
+      .rpc();
+
+    await program.methods
+      .publishPaper()
+      .accounts({
+        authority: author.publicKey,
+        paper: paperPda,
+        authorProfile: pda(Buffer.from("researcher"), author.publicKey.toBuffer()),
+        programState: programStatePda,
+        categoryIndex: pda(Buffer.from("category"), Buffer.from([0])),
+        categoryPage: pda(
+          Buffer.from("category-page"),
+          Buffer.from([0]),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 4)
+        ),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([author])
+      .rpc();
+
+    await program.methods
+      .fundPaper(new anchor.BN(2_000_000))
+      .accounts({
+        funder: funder.publicKey,
+        paper: paperPda,
+        authorProfile: pda(Buffer.from("researcher"), author.publicKey.toBuffer()),
+        funderTokenAccount,
+        paperTokenAccount: paperTokenPda,
+        platformTokenAccount: platformVaultPda,
+        funding: fundingPda,
+        programState: programStatePda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([funder])
+      .rpc();
+  });
+
+  it("Anyone can expire a paper once its funding deadline passes", async () => {
+    const clock = await context.banksClient.getClock();
+    context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(2 * DAY)
+      )
+    );
+
+    await program.methods.expirePaper().accounts({ paper: paperPda }).rpc();
+
+    const paper = await program.account.researchPaper.fetch(paperPda);
+    assert.equal(paper.status.expired !== undefined, true);
+  });
+
+  it("Should fail to refund while refunds are paused", async () => {
+    await program.methods
+      .setPauseFlags(PAUSE_REFUNDS)
+      .accounts({ admin: provider.wallet.publicKey, programState: programStatePda })
+      .rpc();
+
+    try {
+      await program.methods.refundFunding().accounts(refundAccounts()).signers([funder]).rpc();
+
+      assert.fail("Should have failed");
+    } catch (error) {
+      expect(error.message).to.include("ProgramPaused");
+    }
+
+    await program.methods
+      .clearPauseFlags(PAUSE_REFUNDS)
+      .accounts({ admin: provider.wallet.publicKey, programState: programStatePda })
+      .rpc();
+  });
+
+  it("Funder is refunded from an expired paper", async () => {
+    await program.methods.refundFunding().accounts(refundAccounts()).signers([funder]).rpc();
+
+    const paper = await program.account.researchPaper.fetch(paperPda);
+    const escrow = await getAccount(provider.connection, paperTokenPda);
+    const funderAccount = await getAccount(provider.connection, funderTokenAccount);
+    assert.equal(paper.fundingCurrent.toNumber(), 0);
+    assert.equal(Number(escrow.amount), 0);
+    assert.equal(Number(funderAccount.amount), 10_000_000 - 50_000); // The 2.5% fee is kept
+    assert.isNull(await program.account.funding.fetchNullable(fundingPda));
+  });
+
+  it("Author can close an expired paper once every funder is refunded", async () => {
+    await program.methods
+      .closePaper()
+      .accounts({
+        author: author.publicKey,
+        paper: paperPda,
+        metadata: pda(Buffer.from("paper-metadata"), paperPda.toBuffer()),
+        retraction: null,
+      })
+      .signers([author])
+      .rpc();
+
+    assert.isNull(await program.account.researchPaper.fetchNullable(paperPda));
+  });
+});
@@ -0,0 +1,425 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BioxResearch } from "../target/types/biox_research";
+import { Keypair, PublicKey, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ACCOUNT_SIZE,
+  AccountLayout,
+  AccountState,
+  MINT_SIZE,
+  MintLayout,
+  getAccount,
+} from "@solana/spl-token";
+import { BankrunProvider, startAnchor } from "anchor-bankrun";
+import { ProgramTestContext } from "solana-bankrun";
+import { assert, expect } from "chai";
+import { createHash } from "crypto";
+
+// Accounts in the layouts the program shipped with can't be created through its current
+// instructions, so they are written directly into an in-process bank and migrated there
+describe("biox_research migration", () => {
+  const DAY = 24 * 60 * 60;
+  const PROGRAM_STATE_SPACE = 248; // ProgramState::SPACE, excluding the discriminator
+
+  let context: ProgramTestContext;
+  let provider: BankrunProvider;
+  let program: Program<BioxResearch>;
+
+  const mint = Keypair.generate().publicKey;
+
+  const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, program.programId);
+
+  const discriminator = (account: string) =>
+    createHash("sha256").update(`account:${account}`).digest().subarray(0, 8);
+
+  const u64 = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+  const u32 = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 4);
+  const str = (value: string) => Buffer.concat([u32(Buffer.byteLength(value)), Buffer.from(value)]);
+
+  const fundWallet = (wallet: PublicKey) =>
+    context.setAccount(wallet, {
+      lamports: 10 * LAMPORTS_PER_SOL,
+      data: Buffer.alloc(0),
+      owner: SystemProgram.programId,
+      executable: false,
+    });
+
+  const setTokenAccount = (address: PublicKey, owner: PublicKey, amount: bigint) => {
+    const data = Buffer.alloc(ACCOUNT_SIZE);
+    AccountLayout.encode(
+      {
+        mint,
+        owner,
+        amount,
+        delegateOption: 0,
+        delegate: PublicKey.default,
+        state: AccountState.Initialized,
+        isNativeOption: 0,
+        isNative: BigInt(0),
+        delegatedAmount: BigInt(0),
+        closeAuthorityOption: 0,
+        closeAuthority: PublicKey.default,
+      },
+      data
+    );
+    context.setAccount(address, {
+      lamports: LAMPORTS_PER_SOL,
+      data,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+    });
+  };
+
+  // A paper in the original layout, addressed by its global id and ending at the bump
+  const writeVersion0Paper = (
+    id: number,
+    author: PublicKey,
+    ipfsHash: string,
+    status: number,
+    fundingGoal: number,
+    fundingDeadline: number
+  ) => {
+    const [paperPda, bump] = pda(Buffer.from("paper"), u64(id));
+    const data = Buffer.concat([
+      discriminator("ResearchPaper"),
+      u64(id),
+      author.toBuffer(),
+      str("Legacy Paper"),
+      str("Submitted before the metadata split."),
+      str(ipfsHash),
+      u32(1),
+      str("Dr. Legacy"), // authors
+      u64(0), // created_at
+      u64(0), // updated_at
+      Buffer.from([status === 0 ? 0 : 1]), // is_published
+      u64(fundingGoal),
+      u64(0), // funding_current
+      u64(fundingDeadline),
+      u64(0), // upvotes
+      u64(0), // downvotes
+      Buffer.from([status]),
+      u32(0), // review_score
+      u32(0), // review_count
+      Buffer.from([bump]),
+    ]);
+    context.setAccount(paperPda, {
+      lamports: LAMPORTS_PER_SOL,
+      data,
+      owner: program.programId,
+      executable: false,
+    });
+    return paperPda;
+  };
+
+  const migratePaper = (paper: PublicKey) =>
+    program.methods
+      .migrateResearchPaper()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        paper,
+        metadata: pda(Buffer.from("paper-metadata"), paper.toBuffer()),
+        programState: pda(Buffer.from("program-state"))[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+  before(async () => {
+    context = await startAnchor("", [], []);
+    provider = new BankrunProvider(context);
+    program = new Program<BioxResearch>(anchor.workspace.BioxResearch.idl, provider);
+
+    const mintData = Buffer.alloc(MINT_SIZE);
+    MintLayout.encode(
+      {
+        mintAuthorityOption: 1,
+        mintAuthority: provider.wallet.publicKey,
+        supply: BigInt(10_000_000),
+        decimals: 6,
+        isInitialized: true,
+        freezeAuthorityOption: 0,
+        freezeAuthority: PublicKey.default,
+      },
+      mintData
+    );
+    context.setAccount(mint, {
+      lamports: LAMPORTS_PER_SOL,
+      data: mintData,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+    });
+
+    // The vault signs for itself, so it is its own token authority
+    const [platformVaultPda] = pda(Buffer.from("platform-vault"));
+    setTokenAccount(platformVaultPda, platformVaultPda, BigInt(0));
+  });
+
+  it("Admin migrates a version 0 program state to the current layout", async () => {
+    const [programStatePda, bump] = pda(Buffer.from("program-state"));
+
+    // The original layout: no version byte, a bool pause flag and nothing after the bump
+    const legacy = Buffer.concat([
+      discriminator("ProgramState"),
+      provider.wallet.publicKey.toBuffer(),
+      u64(7), // paper_count
+      u64(42_000_000), // total_funding
+      new anchor.BN(300).toArrayLike(Buffer, "le", 2), // platform_fee_rate
+      u64(2_000_000), // min_funding_goal
+      u64(60 * DAY), // max_funding_period
+      Buffer.from([1, bump]), // is_paused, bump
+    ]);
+    context.setAccount(programStatePda, {
+      lamports: LAMPORTS_PER_SOL,
+      data: legacy,
+      owner: program.programId,
+      executable: false,
+    });
+
+    await program.methods
+      .migrateProgramState()
+      .accounts({
+        admin: provider.wallet.publicKey,
+        programState: programStatePda,
+        governanceMint: null,
+      })
+      .rpc();
+
+    const account = await context.banksClient.getAccount(programStatePda);
+    assert.equal(account.data.length, 8 + PROGRAM_STATE_SPACE);
+
+    const state = await program.account.programState.fetch(programStatePda);
+    // Fields from the original layout carry over
+    assert.equal(state.admin.toString(), provider.wallet.publicKey.toString());
+    assert.equal(state.paperCount.toNumber(), 7);
+    assert.equal(state.totalFunding.toNumber(), 42_000_000);
+    assert.equal(state.platformFeeRate, 300);
+    assert.equal(state.minFundingGoal.toNumber(), 2_000_000);
+    assert.equal(state.maxFundingPeriod.toNumber(), 60 * DAY);
+    assert.equal(state.bump, bump);
+    // A paused program stays paused for everything but claims and refunds
+    assert.equal(state.pauseFlags, (1 << 0) | (1 << 1) | (1 << 2) | (1 << 4));
+    // Fields added since then get their defaults
+    assert.equal(state.version, 4);
+    assert.isNull(state.pendingAdmin);
+    assert.equal(state.settingsDelay.toNumber(), 2 * DAY);
+    assert.equal(state.voteWeightDivisor.toNumber(), 1);
+    assert.equal(state.voteWeightCap.toNumber(), 10);
+    assert.equal(state.governanceMint.toString(), PublicKey.default.toString());
+    assert.equal(state.governanceQuorum.toNumber(), 0);
+    assert.equal(state.governanceThresholdBps, 5000);
+    assert.equal(state.governanceVotingPeriod.toNumber(), 3 * DAY);
+    assert.equal(state.proposalCount.toNumber(), 0);
+    assert.isDefined(state.votingMode.linear);
+    assert.equal(state.voteCreditsPerEpoch.toNumber(), 100);
+    assert.equal(state.voteEpochDuration.toNumber(), 7 * DAY);
+    assert.equal(state.governanceDecimals, 0);
+    assert.equal(state.voteWeightZeroBalance.toNumber(), 1);
+    assert.isDefined(state.voteWeightCurve.linear);
+    assert.equal(state.votingWindowDuration.toNumber(), 30 * DAY);
+    assert.deepEqual(state.reserved, new Array(29).fill(0));
+  });
+
+  it("A paper whose legacy hash is not a CID migrates without a manuscript", async () => {
+    const [programStatePda] = pda(Buffer.from("program-state"));
+    const author = Keypair.generate();
+    fundWallet(author.publicKey);
+
+    // Lift the pause carried over from the legacy program state
+    await program.methods
+      .clearPauseFlags((1 << 0) | (1 << 1) | (1 << 2) | (1 << 4))
+      .accounts({ admin: provider.wallet.publicKey, programState: programStatePda })
+      .rpc();
+
+    // Early clients stored arbitrary strings here; this one is not base58
+    const paperPda = writeVersion0Paper(
+      1_000,
+      author.publicKey,
+      "QmXoYLX8...lM5nO",
+      1, // Published
+      1_000_000,
+      30 * DAY
+    );
+    await migratePaper(paperPda);
+
+    let paper = await program.account.researchPaper.fetch(paperPda);
+    assert.equal(paper.version, 9);
+    assert.isTrue(paper.legacySeeds);
+    assert.isDefined(paper.status.published);
+    assert.equal(paper.contentId.codec, 0);
+    assert.deepEqual(paper.contentId.digest, new Array(32).fill(0));
+
+    // The author supplies the real manuscript even though the paper is past its draft
+    const digest = createHash("sha256").update("legacy manuscript").digest();
+    const cid = "f01551220" + digest.toString("hex");
+    const registryPda = pda(Buffer.from("manuscript"), digest)[0];
+    await program.methods
+      .updatePaper(null, null, cid, null, null)
+      .accounts({
+        author: author.publicKey,
+        paper: paperPda,
+        metadata: pda(Buffer.from("paper-metadata"), paperPda.toBuffer()),
+        manuscriptRegistry: registryPda,
+        previousManuscriptRegistry: pda(Buffer.from("manuscript"), Buffer.alloc(32))[0],
+        programState: programStatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([author])
+      .rpc();
+
+    paper = await program.account.researchPaper.fetch(paperPda);
+    assert.equal(paper.contentId.version, 1);
+    assert.equal(paper.contentId.codec, 0x55);
+    assert.deepEqual(paper.contentId.digest, Array.from(digest));
+    const registry = await program.account.manuscriptRegistry.fetch(registryPda);
+    assert.equal(registry.paper.toString(), paperPda.toString());
+
+    // Once set, the manuscript of a published paper changes only through revisions
+    try {
+      await program.methods
+        .updatePaper(null, null, "f01551220" + "ab".repeat(32), null, null)
+        .accounts({
+          author: author.publicKey,
+          paper: paperPda,
+          metadata: pda(Buffer.from("paper-metadata"), paperPda.toBuffer()),
+          manuscriptRegistry: pda(Buffer.from("manuscript"), Buffer.alloc(32, 0xab))[0],
+          previousManuscriptRegistry: registryPda,
+          programState: programStatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (error) {
+      expect(error.message).to.include("InvalidPaperStatus");
+    }
+  });
+
+  describe("A paper addressed by its global id", () => {
+    const PAPER_ID = 1_001;
+    const author = Keypair.generate();
+    const funder = Keypair.generate();
+    const backer = Keypair.generate();
+    const funderTokenAccount = Keypair.generate().publicKey;
+    const backerTokenAccount = Keypair.generate().publicKey;
+    const authorTokenAccount = Keypair.generate().publicKey;
+    let paperPda: PublicKey;
+    let paperTokenPda: PublicKey;
+
+    const profilePda = () => pda(Buffer.from("researcher"), author.publicKey.toBuffer())[0];
+
+    const fund = (wallet: Keypair, tokenAccount: PublicKey, amount: number) =>
+      program.methods
+        .fundPaper(new anchor.BN(amount))
+        .accounts({
+          funder: wallet.publicKey,
+          paper: paperPda,
+          authorProfile: profilePda(),
+          funderTokenAccount: tokenAccount,
+          paperTokenAccount: paperTokenPda,
+          platformTokenAccount: pda(Buffer.from("platform-vault"))[0],
+          funding: pda(Buffer.from("funding"), u64(PAPER_ID), wallet.publicKey.toBuffer())[0],
+          programState: pda(Buffer.from("program-state"))[0],
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+    before(async () => {
+      fundWallet(author.publicKey);
+      fundWallet(funder.publicKey);
+      fundWallet(backer.publicKey);
+
+      const clock = await context.banksClient.getClock();
+      const digest = createHash("sha256").update("legacy funded paper").digest();
+      paperPda = writeVersion0Paper(
+        PAPER_ID,
+        author.publicKey,
+        "f01551220" + digest.toString("hex"),
+        1, // Published
+        5_000_000,
+        Number(clock.unixTimestamp) + 30 * DAY
+      );
+      await migratePaper(paperPda);
+
+      // Child accounts of these papers stay seeded by the id rather than the address
+      [paperTokenPda] = pda(Buffer.from("paper-token"), u64(PAPER_ID));
+      setTokenAccount(paperTokenPda, paperTokenPda, BigInt(0));
+      setTokenAccount(funderTokenAccount, funder.publicKey, BigInt(10_000_000));
+      setTokenAccount(backerTokenAccount, backer.publicKey, BigInt(10_000_000));
+      setTokenAccount(authorTokenAccount, author.publicKey, BigInt(0));
+    });
+
+    it("Funding creates the profile its author never had", async () => {
+      const [fundingPda] = pda(Buffer.from("funding"), u64(PAPER_ID), funder.publicKey.toBuffer());
+      assert.isNull(await context.banksClient.getAccount(profilePda()));
+
+      await fund(funder, funderTokenAccount, 2_000_000);
+
+      const funding = await program.account.funding.fetch(fundingPda);
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      const profile = await program.account.researcherProfile.fetch(profilePda());
+      assert.equal(paper.fundingCurrent.toNumber(), funding.amount.toNumber());
+      assert.equal(profile.wallet.toString(), author.publicKey.toString());
+      assert.equal(profile.totalFundingRaised.toNumber(), funding.amount.toNumber());
+      assert.equal(profile.papersSubmitted, 0);
+    });
+
+    it("Votes on it are keyed by its id", async () => {
+      const [votePda] = pda(Buffer.from("vote"), u64(PAPER_ID), funder.publicKey.toBuffer());
+
+      await program.methods
+        .votePaper(true, new anchor.BN(0))
+        .accounts({
+          voter: funder.publicKey,
+          paper: paperPda,
+          programState: pda(Buffer.from("program-state"))[0],
+          voteEscrow: null,
+          voterCredits: null,
+          vote: votePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+      const vote = await program.account.vote.fetch(votePda);
+      const paper = await program.account.researchPaper.fetch(paperPda);
+      assert.equal(vote.paperId.toNumber(), PAPER_ID);
+      assert.equal(vote.weight.toNumber(), 1); // The zero-balance weight
+      assert.equal(paper.upvotes.toNumber(), 1);
+    });
+
+    it("Author claims its escrow once it is fully funded", async () => {
+      await fund(backer, backerTokenAccount, 4_000_000);
+
+      let paper = await program.account.researchPaper.fetch(paperPda);
+      assert.isDefined(paper.status.fullyFunded);
+      const escrow = await getAccount(provider.connection, paperTokenPda);
+      assert.equal(Number(escrow.amount), paper.fundingCurrent.toNumber());
+
+      await program.methods
+        .claimFunds()
+        .accounts({
+          author: author.publicKey,
+          paper: paperPda,
+          authorProfile: profilePda(),
+          paperTokenAccount: paperTokenPda,
+          authorTokenAccount,
+          programState: pda(Buffer.from("program-state"))[0],
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      paper = await program.account.researchPaper.fetch(paperPda);
+      const claimed = await getAccount(provider.connection, authorTokenAccount);
+      const profile = await program.account.researcherProfile.fetch(profilePda());
+      assert.isDefined(paper.status.completed);
+      assert.equal(claimed.amount, escrow.amount);
+      assert.equal(Number((await getAccount(provider.connection, paperTokenPda)).amount), 0);
+      assert.equal(profile.papersCompleted, 1);
+    });
+  });
+});